@@ -1,4 +1,5 @@
 
+use anyhow::Context;
 use clap::Parser;
 use m0_common::{config::Config, logging};
 use tracing::info;
@@ -8,6 +9,10 @@ use m0_core::pipeline::ingest::IngestRuntime;
 struct Args {
     #[arg(long)]
     config: String,
+
+    /// Config override applied after `M0_*` env vars, e.g. `--set engine.tick_interval_ms=500`.
+    #[arg(long = "set", value_name = "KEY=VALUE")]
+    overrides: Vec<String>,
 }
 
 #[tokio::main]
@@ -15,7 +20,8 @@ async fn main() -> anyhow::Result<()> {
     let args = Args::parse();
     logging::init("m0-ingestd");
 
-    let _cfg = Config::load_toml_file(&args.config).unwrap_or_default();
+    let _cfg = Config::load(&args.config, &args.overrides)
+        .with_context(|| format!("loading config {}", args.config))?;
     let markets = vec!["NBA_LAL_BOS".to_string(), "POL_US_ELECTION".to_string(), "MACRO_CPI_US".to_string()];
 
     let mut ingest = IngestRuntime::start_simulated(&markets).await?;
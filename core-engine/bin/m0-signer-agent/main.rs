@@ -1,4 +1,5 @@
 
+use anyhow::Context;
 use clap::Parser;
 use m0_common::{config::Config, logging};
 use m0_signer::{keyring::local::LocalKey, tx_submit::submit_tx_simulated};
@@ -9,8 +10,9 @@ struct Args {
     #[arg(long)]
     config: String,
 
-    #[arg(long, default_value = "devnet")]
-    cluster: String,
+    /// Config override applied after `M0_*` env vars, e.g. `--set engine.tick_interval_ms=500`.
+    #[arg(long = "set", value_name = "KEY=VALUE")]
+    overrides: Vec<String>,
 }
 
 #[tokio::main]
@@ -18,11 +20,13 @@ async fn main() -> anyhow::Result<()> {
     let args = Args::parse();
     logging::init("m0-signer-agent");
 
-    let _cfg = Config::load_toml_file(&args.config).unwrap_or_default();
+    let cfg = Config::load(&args.config, &args.overrides)
+        .with_context(|| format!("loading config {}", args.config))?;
     let key = LocalKey::load_from_env()?;
     info!(secret_prefix=%hex::encode(&key.secret[..4]), "loaded signer key (ephemeral skeleton)");
 
-    let sig = submit_tx_simulated(&args.cluster, b"payload").await?;
-    info!(tx_sig=%sig, "submitted simulated tx");
+    let cluster = cfg.solana.cluster.as_str();
+    let sig = submit_tx_simulated(cluster, b"payload").await?;
+    info!(tx_sig=%sig, cluster, "submitted simulated tx");
     Ok(())
 }
@@ -1,14 +1,21 @@
 
+use anyhow::Context;
 use clap::Parser;
 use m0_common::{config::Config, logging};
 use tracing::{info, warn};
 use m0_core::pipeline::{ingest::IngestRuntime, normalize::normalize_event, feature::make_features, model::predict_two_outcome, calibrate::calibrate, bundle::build_bundle};
 use m0_signer::{commit::commit_hash, replay_protection::ReplayState, reveal::signature_message};
 
+const BUNDLE_SCHEMA_VERSION: u16 = 1;
+
 #[derive(Parser, Debug)]
 struct Args {
     #[arg(long)]
     config: String,
+
+    /// Config override applied after `M0_*` env vars, e.g. `--set engine.tick_interval_ms=500`.
+    #[arg(long = "set", value_name = "KEY=VALUE")]
+    overrides: Vec<String>,
 }
 
 #[tokio::main]
@@ -16,8 +23,9 @@ async fn main() -> anyhow::Result<()> {
     let args = Args::parse();
     logging::init("m0d");
 
-    let cfg = Config::load_toml_file(&args.config).unwrap_or_default();
-    info!(env=%cfg.env, "engine starting");
+    let cfg = Config::load(&args.config, &args.overrides)
+        .with_context(|| format!("loading config {}", args.config))?;
+    info!(env=%cfg.env.name, cluster=?cfg.solana.cluster, "engine starting");
 
     let markets = vec![
         "NBA_LAL_BOS".to_string(),
@@ -28,7 +36,7 @@ async fn main() -> anyhow::Result<()> {
     let mut replay = ReplayState::default();
 
     let mut tick: u32 = 0;
-    let mut interval = m0_core::runtime::scheduler::tick_interval(cfg.engine.tick_ms);
+    let mut interval = m0_core::runtime::scheduler::tick_interval(cfg.engine.tick_interval_ms);
 
     loop {
         interval.tick().await;
@@ -36,7 +44,7 @@ async fn main() -> anyhow::Result<()> {
 
         // Consume up to N events per tick (simple).
         let mut processed = 0usize;
-        while processed < cfg.engine.max_markets_per_tick {
+        while processed < cfg.engine.bundle_max_markets as usize {
             match ingest.consumer.recv().await {
                 Some(raw) => {
                     let canon = match normalize_event(&raw) {
@@ -50,12 +58,14 @@ async fn main() -> anyhow::Result<()> {
                     calibrate(&mut probs);
 
                     let sequence = replay.next()?;
-                    let (bundle, bundle_bytes, content_hash) = build_bundle(cfg.engine.schema_version, 1, 1, &canon.market_id, 1, tick, sequence, 0, &probs)?;
+                    let (bundle, bundle_bytes, content_hash) = build_bundle(BUNDLE_SCHEMA_VERSION, 1, 1, &canon.market_id, 1, tick, sequence, 0, &probs)?;
 
                     // Commit/reveal message construction (client side)
                     let salt = [7u8; 32];
                     let commit = commit_hash(&content_hash, &salt);
-                    let sig_msg = signature_message(&content_hash, bundle.signer_set_id, bundle.publish_epoch_id, sequence);
+                    // Market/Epoch PDAs come from the chain client; not derived in this simulation.
+                    let (market_pda, epoch_pda) = ([0u8; 32], [0u8; 32]);
+                    let sig_msg = signature_message(&content_hash, &market_pda, &epoch_pda, bundle.signer_set_id, bundle.publish_epoch_id, sequence);
 
                     info!(
                         market_id=%canon.market_id,
@@ -1,5 +1,5 @@
 
-use crate::format::Bundle;
+use crate::format::{Bundle, MarketReveal, OutcomePoint};
 
 pub fn encode_json(bundle: &Bundle) -> anyhow::Result<Vec<u8>> {
     Ok(serde_json::to_vec(bundle)?)
@@ -8,3 +8,55 @@ pub fn encode_json(bundle: &Bundle) -> anyhow::Result<Vec<u8>> {
 pub fn decode_json(bytes: &[u8]) -> anyhow::Result<Bundle> {
     Ok(serde_json::from_slice(bytes)?)
 }
+
+// Canonical encoding used for hashing and on-chain reveal.
+// Byte-compatible with the Borsh serialization of m0_oracle::state::reveal::BundleReveal:
+// little-endian integers, u32 length prefixes for strings and vectors.
+
+pub fn encode_canonical(bundle: &Bundle) -> Vec<u8> {
+    let mut out = Vec::with_capacity(64 + bundle.markets.len() * 128);
+    out.extend_from_slice(&bundle.schema_version.to_le_bytes());
+    out.extend_from_slice(&bundle.signer_set_id.to_le_bytes());
+    out.extend_from_slice(&bundle.publish_epoch_id.to_le_bytes());
+    out.extend_from_slice(&bundle.created_at_ms.to_le_bytes());
+    out.extend_from_slice(&bundle.bundle_id.as_bytes16());
+    out.extend_from_slice(&(bundle.markets.len() as u32).to_le_bytes());
+    for m in &bundle.markets {
+        encode_market(&mut out, m);
+    }
+    out
+}
+
+pub fn encode_market_canonical(market: &MarketReveal) -> Vec<u8> {
+    let mut out = Vec::with_capacity(128);
+    encode_market(&mut out, market);
+    out
+}
+
+fn encode_str(out: &mut Vec<u8>, s: &str) {
+    out.extend_from_slice(&(s.len() as u32).to_le_bytes());
+    out.extend_from_slice(s.as_bytes());
+}
+
+fn encode_market(out: &mut Vec<u8>, m: &MarketReveal) {
+    encode_str(out, &m.market_id);
+    out.extend_from_slice(&m.epoch_id.to_le_bytes());
+    out.extend_from_slice(&m.tick_index.to_le_bytes());
+    out.extend_from_slice(&m.sequence.to_le_bytes());
+    out.extend_from_slice(&m.observed_at_ms.to_le_bytes());
+    out.extend_from_slice(&m.risk_score.to_le_bytes());
+    out.extend_from_slice(&m.quality_flags.to_le_bytes());
+    out.extend_from_slice(&(m.outcomes.len() as u32).to_le_bytes());
+    for o in &m.outcomes {
+        encode_outcome(out, o);
+    }
+}
+
+fn encode_outcome(out: &mut Vec<u8>, o: &OutcomePoint) {
+    encode_str(out, &o.outcome_id);
+    out.extend_from_slice(&o.p_scaled.to_le_bytes());
+    out.extend_from_slice(&o.ci_low_scaled.to_le_bytes());
+    out.extend_from_slice(&o.ci_high_scaled.to_le_bytes());
+    out.extend_from_slice(&o.ci_level_bps.to_le_bytes());
+    out.extend_from_slice(&o.quality_flags.to_le_bytes());
+}
@@ -1,7 +1,118 @@
 
-// Optional merkle chunking support for very large bundles.
-// This skeleton keeps API placeholders.
+use sha2::{Digest, Sha256};
+use crate::codec::{encode_canonical, encode_market_canonical};
+use crate::format::Bundle;
 
-pub fn merkle_root(_chunks: &[Vec<u8>]) -> [u8; 32] {
-    [0u8; 32]
+// Merkle chunking support for bundles that exceed single-transaction reveal limits.
+// Layout follows docs/engine-spec/bundle-hashing.md (section 6):
+// - chunk_hash = sha256("M0_CHUNK_V1" || u32le(chunk_index) || chunk_bytes)
+// - node       = sha256("M0_MERKLE_NODE_V1" || left || right)
+// - an odd node at any level is paired with itself
+// - commitment = sha256("M0_MERKLE_COMMITMENT_V1" || root || u32le(chunk_count))
+// In merkle mode the commitment takes the place of bundle_content_hash (commit + signatures).
+
+pub const DEFAULT_CHUNK_SIZE: usize = 900;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleProof {
+    pub chunk_index: u32,
+    pub siblings: Vec<[u8; 32]>,
+}
+
+pub fn chunk_hash(chunk_index: u32, chunk: &[u8]) -> [u8; 32] {
+    let mut h = Sha256::new();
+    h.update(b"M0_CHUNK_V1");
+    h.update(chunk_index.to_le_bytes());
+    h.update(chunk);
+    h.finalize().into()
+}
+
+pub fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut h = Sha256::new();
+    h.update(b"M0_MERKLE_NODE_V1");
+    h.update(left);
+    h.update(right);
+    h.finalize().into()
+}
+
+/// Binds the chunk count to the root; signed and committed instead of the bare root.
+pub fn merkle_commitment(root: &[u8; 32], chunk_count: u32) -> [u8; 32] {
+    let mut h = Sha256::new();
+    h.update(b"M0_MERKLE_COMMITMENT_V1");
+    h.update(root);
+    h.update(chunk_count.to_le_bytes());
+    h.finalize().into()
+}
+
+/// Splits canonical content bytes into fixed-size chunks (last chunk may be shorter).
+pub fn split_chunks(content: &[u8], chunk_size: usize) -> Vec<Vec<u8>> {
+    content.chunks(chunk_size.max(1)).map(|c| c.to_vec()).collect()
+}
+
+/// One chunk per market, so a consumer can verify a single market without the full bundle.
+/// Markets are sorted by market_id to keep chunk indices deterministic.
+pub fn market_chunks(bundle: &Bundle) -> Vec<Vec<u8>> {
+    let mut markets: Vec<_> = bundle.markets.iter().collect();
+    markets.sort_by(|a, b| a.market_id.cmp(&b.market_id));
+    markets.into_iter().map(encode_market_canonical).collect()
+}
+
+/// Chunks the canonical bundle encoding with DEFAULT_CHUNK_SIZE.
+pub fn bundle_chunks(bundle: &Bundle) -> Vec<Vec<u8>> {
+    split_chunks(&encode_canonical(bundle), DEFAULT_CHUNK_SIZE)
+}
+
+fn leaves(chunks: &[Vec<u8>]) -> Vec<[u8; 32]> {
+    chunks.iter().enumerate().map(|(i, c)| chunk_hash(i as u32, c)).collect()
+}
+
+fn next_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [l, r] => node_hash(l, r),
+            [l] => node_hash(l, l),
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+/// Returns the all-zero root for an empty chunk list.
+pub fn merkle_root(chunks: &[Vec<u8>]) -> [u8; 32] {
+    let mut level = leaves(chunks);
+    if level.is_empty() {
+        return [0u8; 32];
+    }
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+    level[0]
+}
+
+pub fn merkle_proof(chunks: &[Vec<u8>], chunk_index: u32) -> Option<MerkleProof> {
+    let mut idx = chunk_index as usize;
+    let mut level = leaves(chunks);
+    if idx >= level.len() {
+        return None;
+    }
+
+    let mut siblings = Vec::new();
+    while level.len() > 1 {
+        let sibling = if idx & 1 == 0 { level.get(idx + 1).unwrap_or(&level[idx]) } else { &level[idx - 1] };
+        siblings.push(*sibling);
+        level = next_level(&level);
+        idx /= 2;
+    }
+
+    Some(MerkleProof { chunk_index, siblings })
+}
+
+pub fn verify_proof(root: &[u8; 32], chunk: &[u8], proof: &MerkleProof) -> bool {
+    let mut acc = chunk_hash(proof.chunk_index, chunk);
+    let mut idx = proof.chunk_index;
+    for s in &proof.siblings {
+        acc = if idx & 1 == 0 { node_hash(&acc, s) } else { node_hash(s, &acc) };
+        idx /= 2;
+    }
+    idx == 0 && &acc == root
 }
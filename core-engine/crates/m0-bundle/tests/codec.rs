@@ -0,0 +1,40 @@
+use m0_bundle::codec::encode_canonical;
+use m0_bundle::format::{Bundle, MarketReveal, OutcomePoint};
+use m0_common::ids::BundleId;
+
+#[test]
+fn canonical_layout_is_length_prefixed_le() {
+    let b = Bundle {
+        schema_version: 1,
+        signer_set_id: 7,
+        publish_epoch_id: 3,
+        created_at_ms: 42,
+        bundle_id: BundleId::new(),
+        markets: vec![MarketReveal {
+            market_id: "NBA".into(),
+            epoch_id: 3,
+            tick_index: 0,
+            sequence: 1,
+            observed_at_ms: 42,
+            risk_score: 0,
+            quality_flags: 0,
+            outcomes: vec![OutcomePoint {
+                outcome_id: "HOME".into(),
+                p_scaled: 1_000_000_000,
+                ci_low_scaled: 1_000_000_000,
+                ci_high_scaled: 1_000_000_000,
+                ci_level_bps: 9_500,
+                quality_flags: 0,
+            }],
+        }],
+    };
+
+    let bytes = encode_canonical(&b);
+    assert_eq!(&bytes[0..2], &1u16.to_le_bytes());
+    assert_eq!(&bytes[2..10], &7u64.to_le_bytes());
+    assert_eq!(&bytes[42..46], &1u32.to_le_bytes()); // market count
+    assert_eq!(&bytes[46..50], &3u32.to_le_bytes()); // market_id len
+    assert_eq!(&bytes[50..53], b"NBA");
+    // header 46 + market (4+3 + 8+4+8+8+2+4 + 4) + outcome (4+4 + 8+8+8+2+4)
+    assert_eq!(bytes.len(), 46 + 45 + 38);
+}
@@ -0,0 +1,40 @@
+use m0_bundle::merkle::{merkle_commitment, merkle_proof, merkle_root, split_chunks, verify_proof};
+
+#[test]
+fn proofs_verify_for_every_chunk() {
+    let content: Vec<u8> = (0..5000u32).map(|i| (i % 251) as u8).collect();
+    let chunks = split_chunks(&content, 900);
+    let root = merkle_root(&chunks);
+
+    for (i, c) in chunks.iter().enumerate() {
+        let proof = merkle_proof(&chunks, i as u32).unwrap();
+        assert!(verify_proof(&root, c, &proof));
+    }
+}
+
+#[test]
+fn proof_rejects_wrong_chunk() {
+    let chunks = split_chunks(b"m0 merkle chunk test payload", 8);
+    let root = merkle_root(&chunks);
+    let proof = merkle_proof(&chunks, 1).unwrap();
+    assert!(!verify_proof(&root, &chunks[2], &proof));
+    assert!(merkle_proof(&chunks, chunks.len() as u32).is_none());
+}
+
+#[test]
+fn commitment_binds_chunk_count() {
+    let chunks = split_chunks(b"m0 merkle chunk test payload", 8);
+    let root = merkle_root(&chunks);
+    let n = chunks.len() as u32;
+    assert_ne!(merkle_commitment(&root, n), merkle_commitment(&root, n + 1));
+    assert_ne!(merkle_commitment(&root, n), merkle_commitment(&root, n - 1));
+}
+
+// Same vector as m0-oracle tests/hashing.rs (hash_merkle_commitment).
+#[test]
+fn commitment_matches_onchain_vector() {
+    assert_eq!(
+        hex::encode(merkle_commitment(&[0x11; 32], 3)),
+        "71631245954ae68363594b5d3c49a3bfb41ffbc70098f4d4872b0359d20a8678"
+    );
+}
@@ -0,0 +1,138 @@
+use toml::{Table, Value};
+use crate::error::M0Error;
+
+/// Env vars named `M0_<SECTION>__<KEY>` override config keys, e.g.
+/// `M0_ENGINE__GUARDRAILS__MAX_JUMP_BPS=900` sets `engine.guardrails.max_jump_bps`.
+/// Vars without a `__` separator (such as `M0_LOG_JSON`) are not config overrides.
+pub const ENV_PREFIX: &str = "M0_";
+const ENV_SEPARATOR: &str = "__";
+
+pub(crate) fn apply_env(
+    root: &mut Table,
+    vars: impl IntoIterator<Item = (String, String)>,
+) -> Result<(), M0Error> {
+    let mut overrides: Vec<(String, String)> = vars
+        .into_iter()
+        .filter(|(k, _)| k.strip_prefix(ENV_PREFIX).is_some_and(|rest| rest.contains(ENV_SEPARATOR)))
+        .collect();
+    // Deterministic order when two vars target the same key with different casing.
+    overrides.sort();
+
+    for (name, raw) in overrides {
+        let path: Vec<String> = name[ENV_PREFIX.len()..]
+            .split(ENV_SEPARATOR)
+            .map(str::to_ascii_lowercase)
+            .collect();
+        set(root, &path, &raw).map_err(|e| M0Error::Config(format!("{name}: {e}")))?;
+    }
+    Ok(())
+}
+
+pub(crate) fn apply_cli(root: &mut Table, overrides: &[String]) -> Result<(), M0Error> {
+    for o in overrides {
+        let (key, raw) = o
+            .split_once('=')
+            .ok_or_else(|| M0Error::Config(format!("--set {o}: expected KEY=VALUE")))?;
+        let path: Vec<String> = key.trim().split('.').map(str::to_string).collect();
+        set(root, &path, raw).map_err(|e| M0Error::Config(format!("--set {key}: {e}")))?;
+    }
+    Ok(())
+}
+
+fn set(root: &mut Table, path: &[String], raw: &str) -> Result<(), String> {
+    if path.iter().any(|seg| seg.is_empty()) {
+        return Err("empty key segment".into());
+    }
+    let Some((last, parents)) = path.split_last() else {
+        return Err("empty key".into());
+    };
+
+    let mut dotted = String::new();
+    let mut table = root;
+    for seg in parents {
+        let key = resolve_key(table, seg);
+        push_segment(&mut dotted, &key);
+        table = match table.entry(key).or_insert_with(|| Value::Table(Table::new())) {
+            Value::Table(t) => t,
+            _ => return Err(format!("{dotted}: not a table")),
+        };
+    }
+
+    let key = resolve_key(table, last);
+    push_segment(&mut dotted, &key);
+    let value = match table.get(&key) {
+        Some(Value::Table(_)) => return Err(format!("{dotted}: is a table, override one of its keys")),
+        Some(existing) => coerce(existing, raw).map_err(|e| format!("{dotted}: {e}"))?,
+        None => infer(raw),
+    };
+    table.insert(key, value);
+    Ok(())
+}
+
+fn push_segment(dotted: &mut String, seg: &str) {
+    if !dotted.is_empty() {
+        dotted.push('.');
+    }
+    dotted.push_str(seg);
+}
+
+// Env var names are upper-cased, so match existing keys case-insensitively (`mTLS_enabled`).
+fn resolve_key(table: &Table, seg: &str) -> String {
+    if table.contains_key(seg) {
+        return seg.to_string();
+    }
+    table
+        .keys()
+        .find(|k| k.eq_ignore_ascii_case(seg))
+        .cloned()
+        .unwrap_or_else(|| seg.to_string())
+}
+
+// Parse the override as the type already present in the file.
+fn coerce(existing: &Value, raw: &str) -> Result<Value, String> {
+    let trimmed = raw.trim();
+    match existing {
+        Value::String(_) => Ok(Value::String(raw.to_string())),
+        Value::Integer(_) => trimmed
+            .parse()
+            .map(Value::Integer)
+            .map_err(|_| format!("expected an integer, got `{raw}`")),
+        Value::Float(_) => trimmed
+            .parse()
+            .map(Value::Float)
+            .map_err(|_| format!("expected a number, got `{raw}`")),
+        Value::Boolean(_) => match trimmed.to_ascii_lowercase().as_str() {
+            "1" | "true" | "yes" | "on" => Ok(Value::Boolean(true)),
+            "0" | "false" | "no" | "off" => Ok(Value::Boolean(false)),
+            _ => Err(format!("expected a boolean, got `{raw}`")),
+        },
+        Value::Array(_) if trimmed.starts_with('[') => match parse_literal(trimmed) {
+            Some(v @ Value::Array(_)) => Ok(v),
+            _ => Err(format!("expected a TOML array, got `{raw}`")),
+        },
+        // Comma-separated shorthand: `a,b,c`.
+        Value::Array(_) => Ok(Value::Array(
+            trimmed
+                .split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(|s| Value::String(s.to_string()))
+                .collect(),
+        )),
+        Value::Datetime(_) => match parse_literal(trimmed) {
+            Some(v @ Value::Datetime(_)) => Ok(v),
+            _ => Err(format!("expected a datetime, got `{raw}`")),
+        },
+        Value::Table(_) => unreachable!("tables are rejected by the caller"),
+    }
+}
+
+// New keys have no type to follow; take a TOML literal if it parses, else a string.
+fn infer(raw: &str) -> Value {
+    parse_literal(raw.trim()).unwrap_or_else(|| Value::String(raw.to_string()))
+}
+
+fn parse_literal(raw: &str) -> Option<Value> {
+    let mut t: Table = toml::from_str(&format!("v = {raw}")).ok()?;
+    t.remove("v")
+}
@@ -0,0 +1,398 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use toml::{Table, Value};
+use crate::error::M0Error;
+
+mod layers;
+mod validate;
+
+pub use layers::ENV_PREFIX;
+
+// Typed model of config/{dev,staging,prod}.toml.
+// Loading is layered: file, then `M0_*` env overrides, then CLI `--set` overrides.
+// There are no silent defaults; a missing or malformed key fails startup.
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Config {
+    pub env: EnvConfig,
+    pub solana: SolanaConfig,
+    pub programs: ProgramsConfig,
+    pub accounts: AccountsConfig,
+    pub storage: StorageConfig,
+    pub eventlog: EventlogConfig,
+    pub engine: EngineConfig,
+    pub publish: PublishConfig,
+    pub signers: SignersConfig,
+    pub api: ApiConfig,
+    pub security: SecurityConfig,
+    pub paths: PathsConfig,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EnvConfig {
+    pub name: String,
+    pub region: String,
+    pub log_level: LogLevel,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SolanaConfig {
+    pub cluster: Cluster,
+    pub rpc_url: String,
+    pub ws_url: String,
+    pub commitment: Commitment,
+    pub timeout_ms: u64,
+    pub max_retries: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Cluster {
+    Localnet,
+    Devnet,
+    Testnet,
+    MainnetBeta,
+}
+
+impl Cluster {
+    /// Name as written in config files, e.g. `mainnet-beta`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Cluster::Localnet => "localnet",
+            Cluster::Devnet => "devnet",
+            Cluster::Testnet => "testnet",
+            Cluster::MainnetBeta => "mainnet-beta",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Commitment {
+    Processed,
+    Confirmed,
+    Finalized,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProgramsConfig {
+    pub registry_program_id: String,
+    pub oracle_program_id: String,
+    pub vault_program_id: String,
+}
+
+// Each key is either a local keypair file (dev) or a secret-manager ref (staging/prod).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AccountsConfig {
+    pub fee_payer_keypair_path: Option<String>,
+    pub fee_payer_key_ref: Option<String>,
+    pub submitter_keypair_path: Option<String>,
+    pub submitter_key_ref: Option<String>,
+    pub registry_admin_ref: Option<String>,
+    pub pause_guardian_ref: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StorageConfig {
+    pub postgres_url: String,
+    pub redis_url: String,
+    pub clickhouse_url: String,
+    pub object_store: ObjectStore,
+
+    // localfs
+    pub object_store_root: Option<String>,
+
+    // s3
+    pub object_store_bucket: Option<String>,
+    pub object_store_prefix: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ObjectStore {
+    Localfs,
+    S3,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EventlogConfig {
+    pub driver: EventlogDriver,
+    pub max_inflight: u32,
+
+    // nats
+    pub url: Option<String>,
+    pub subject_prefix: Option<String>,
+    pub durable_consumer: Option<String>,
+
+    // kafka
+    pub brokers: Option<Vec<String>>,
+    pub topic_prefix: Option<String>,
+    pub consumer_group: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventlogDriver {
+    Nats,
+    Kafka,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EngineConfig {
+    pub profile: String,
+    pub tick_interval_ms: u64,
+    pub max_concurrent_markets: u32,
+    pub max_inflight_events: u64,
+    pub feature_window_seconds: u64,
+    pub bundle_max_markets: u32,
+    pub connectors: ConnectorsConfig,
+    pub normalization: NormalizationConfig,
+    pub models: ModelsConfig,
+    pub guardrails: GuardrailsConfig,
+}
+
+// Scalar part of [engine]; the sub-tables are read separately so errors carry their path.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EngineSection {
+    profile: String,
+    tick_interval_ms: u64,
+    max_concurrent_markets: u32,
+    max_inflight_events: u64,
+    feature_window_seconds: u64,
+    bundle_max_markets: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorsConfig {
+    pub onchain_enabled: bool,
+    pub sports_enabled: bool,
+    pub politics_enabled: bool,
+    pub macro_enabled: bool,
+    pub crypto_enabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NormalizationConfig {
+    pub strict_schema: bool,
+    pub drop_unknown_fields: bool,
+    pub default_timezone: String,
+    pub max_clock_skew_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ModelsConfig {
+    pub model_family: String,
+    pub calibration_enabled: bool,
+    pub calibration_artifact_path: String,
+    pub prior_strength: f64,
+    pub min_observations: u32,
+    pub max_features: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GuardrailsConfig {
+    pub policy_level: PolicyLevel,
+    pub max_jump_bps: u16,
+    pub max_ci_width_bps: u16,
+    pub min_source_coverage_ratio: f64,
+    pub max_staleness_ms: u64,
+    pub block_on_divergence: bool,
+    pub block_on_ci_invalid: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum PolicyLevel {
+    Fast,
+    Normal,
+    Strict,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PublishConfig {
+    pub enabled: bool,
+    pub mode: PublishMode,
+    pub commit_reveal: bool,
+    pub cadence_ms: u64,
+    pub concurrency: u32,
+    pub compute_unit_limit: u32,
+    pub priority_fee_micro_lamports: u64,
+    pub reveal_delay_ms: u64,
+    pub max_reveal_retries: u32,
+    pub idempotency_store: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PublishMode {
+    CommitReveal,
+    Direct,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SignersConfig {
+    pub active_signer_set_id: u64,
+    pub threshold: u16,
+    pub key_source: KeySource,
+    pub signer_agent_endpoints: Vec<String>,
+    pub request_timeout_ms: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeySource {
+    File,
+    Kms,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ApiConfig {
+    pub http_bind: String,
+    pub ws_bind: String,
+    pub cors_allowed_origins: Vec<String>,
+    pub max_request_body_bytes: u64,
+    pub rate_limit_rps: u32,
+    pub rate_limit_burst: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SecurityConfig {
+    pub require_tls: bool,
+    #[serde(rename = "mTLS_enabled")]
+    pub mtls_enabled: bool,
+    pub jwt_enabled: bool,
+    pub admin_api_enabled: bool,
+    pub admin_token: Option<String>,
+    pub admin_token_ref: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PathsConfig {
+    pub markets_config_dir: String,
+    pub risk_config_dir: String,
+    pub telemetry_dir: String,
+}
+
+impl Config {
+    /// Loads `path`, applies `M0_*` env overrides and then `cli_overrides` (`key.path=value`),
+    /// and validates the result.
+    pub fn load(path: impl AsRef<Path>, cli_overrides: &[String]) -> Result<Self, M0Error> {
+        let path = path.as_ref();
+        let s = std::fs::read_to_string(path)
+            .map_err(|e| M0Error::Io(format!("{}: {e}", path.display())))?;
+        Self::from_layers(&s, std::env::vars(), cli_overrides)
+    }
+
+    /// Same as [`Config::load`] with the file contents and environment passed explicitly.
+    pub fn from_layers(
+        toml_src: &str,
+        env_vars: impl IntoIterator<Item = (String, String)>,
+        cli_overrides: &[String],
+    ) -> Result<Self, M0Error> {
+        let mut root: Table = toml::from_str(toml_src).map_err(|e| M0Error::Config(e.to_string()))?;
+        layers::apply_env(&mut root, env_vars)?;
+        layers::apply_cli(&mut root, cli_overrides)?;
+
+        let cfg = Self::from_table(root)?;
+        cfg.validate()?;
+        Ok(cfg)
+    }
+
+    fn from_table(root: Table) -> Result<Self, M0Error> {
+        let mut s = Sections { root };
+
+        // Sub-tables first so [engine] only holds its scalars when it is read.
+        let connectors = s.take("engine.connectors")?;
+        let normalization = s.take("engine.normalization")?;
+        let models = s.take("engine.models")?;
+        let guardrails = s.take("engine.guardrails")?;
+        let engine: EngineSection = s.take("engine")?;
+
+        let cfg = Self {
+            env: s.take("env")?,
+            solana: s.take("solana")?,
+            programs: s.take("programs")?,
+            accounts: s.take("accounts")?,
+            storage: s.take("storage")?,
+            eventlog: s.take("eventlog")?,
+            engine: EngineConfig {
+                profile: engine.profile,
+                tick_interval_ms: engine.tick_interval_ms,
+                max_concurrent_markets: engine.max_concurrent_markets,
+                max_inflight_events: engine.max_inflight_events,
+                feature_window_seconds: engine.feature_window_seconds,
+                bundle_max_markets: engine.bundle_max_markets,
+                connectors,
+                normalization,
+                models,
+                guardrails,
+            },
+            publish: s.take("publish")?,
+            signers: s.take("signers")?,
+            api: s.take("api")?,
+            security: s.take("security")?,
+            paths: s.take("paths")?,
+        };
+
+        if let Some(unknown) = s.root.keys().next() {
+            return Err(M0Error::Config(format!("{unknown}: unknown section")));
+        }
+        Ok(cfg)
+    }
+}
+
+// Removes and deserializes sections one at a time, prefixing errors with the dotted path.
+struct Sections {
+    root: Table,
+}
+
+impl Sections {
+    fn take<T: DeserializeOwned>(&mut self, path: &str) -> Result<T, M0Error> {
+        let missing = || M0Error::Config(format!("{path}: missing section"));
+
+        let (parents, last) = match path.rsplit_once('.') {
+            Some((p, l)) => (Some(p), l),
+            None => (None, path),
+        };
+        let mut table = &mut self.root;
+        for seg in parents.into_iter().flat_map(|p| p.split('.')) {
+            table = match table.get_mut(seg) {
+                Some(Value::Table(t)) => t,
+                _ => return Err(missing()),
+            };
+        }
+
+        match table.remove(last) {
+            Some(v @ Value::Table(_)) => v.try_into().map_err(|e| M0Error::Config(format!("{path}: {e}"))),
+            Some(_) => Err(M0Error::Config(format!("{path}: expected a table"))),
+            None => Err(missing()),
+        }
+    }
+}
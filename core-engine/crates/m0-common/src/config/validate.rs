@@ -0,0 +1,264 @@
+use std::fmt::Display;
+use std::net::SocketAddr;
+use super::*;
+
+const MAX_BPS: u16 = 10_000;
+// Solana per-transaction compute unit cap.
+const MAX_COMPUTE_UNITS: u32 = 1_400_000;
+
+// Collects every problem so one startup reports all of them, each keyed by dotted path.
+#[derive(Default)]
+struct Issues(Vec<String>);
+
+impl Issues {
+    fn check(&mut self, ok: bool, path: &str, msg: impl Display) {
+        if !ok {
+            self.0.push(format!("{path}: {msg}"));
+        }
+    }
+
+    fn non_empty(&mut self, value: &str, path: &str) {
+        self.check(!value.trim().is_empty(), path, "must not be empty");
+    }
+
+    fn url(&mut self, value: &str, path: &str, schemes: &[&str]) {
+        let ok = value
+            .split_once("://")
+            .is_some_and(|(scheme, rest)| schemes.contains(&scheme) && !rest.is_empty());
+        self.check(ok, path, format_args!("expected a {} url, got `{value}`", schemes.join("/")));
+    }
+
+    fn required<'a>(&mut self, value: &'a Option<String>, path: &str, why: &str) -> Option<&'a str> {
+        match value.as_deref() {
+            Some(v) if !v.trim().is_empty() => Some(v),
+            _ => {
+                self.0.push(format!("{path}: required when {why}"));
+                None
+            }
+        }
+    }
+
+    fn unused<T>(&mut self, value: &Option<T>, path: &str, why: &str) {
+        self.check(value.is_none(), path, format_args!("not allowed when {why}"));
+    }
+
+    // Exactly one of a local keypair path or a secret-manager ref.
+    fn key(&mut self, path: &Option<String>, key_ref: &Option<String>, section: &str, name: &str, prod: bool) {
+        let path_key = format!("{section}.{name}_keypair_path");
+        let ref_key = format!("{section}.{name}_key_ref");
+        match (path, key_ref) {
+            (Some(p), None) => {
+                self.non_empty(p, &path_key);
+                self.check(!prod, &path_key, "keypair files are not allowed on mainnet-beta; use a key ref");
+            }
+            (None, Some(r)) => self.key_ref(r, &ref_key),
+            (Some(_), Some(_)) => self.0.push(format!("{section}: set only one of `{name}_keypair_path` and `{name}_key_ref`")),
+            (None, None) => self.0.push(format!("{section}: one of `{name}_keypair_path` or `{name}_key_ref` is required")),
+        }
+    }
+
+    fn key_ref(&mut self, value: &str, path: &str) {
+        self.url(value, path, &["kms", "hsm", "multisig"]);
+    }
+
+    fn into_result(self) -> Result<(), M0Error> {
+        if self.0.is_empty() {
+            return Ok(());
+        }
+        Err(M0Error::Validation(self.0.join("; ")))
+    }
+}
+
+impl Config {
+    /// Checks cross-field and range constraints. Every failure is reported with its key path.
+    pub fn validate(&self) -> Result<(), M0Error> {
+        let mut v = Issues::default();
+        let prod = self.solana.cluster == Cluster::MainnetBeta;
+
+        v.non_empty(&self.env.name, "env.name");
+        v.non_empty(&self.env.region, "env.region");
+
+        let s = &self.solana;
+        v.url(&s.rpc_url, "solana.rpc_url", &["http", "https"]);
+        v.url(&s.ws_url, "solana.ws_url", &["ws", "wss"]);
+        v.check(s.timeout_ms > 0, "solana.timeout_ms", "must be > 0");
+        if prod {
+            v.check(s.rpc_url.starts_with("https://"), "solana.rpc_url", "must use https on mainnet-beta");
+            v.check(s.ws_url.starts_with("wss://"), "solana.ws_url", "must use wss on mainnet-beta");
+        }
+
+        v.non_empty(&self.programs.registry_program_id, "programs.registry_program_id");
+        v.non_empty(&self.programs.oracle_program_id, "programs.oracle_program_id");
+        v.non_empty(&self.programs.vault_program_id, "programs.vault_program_id");
+
+        let a = &self.accounts;
+        v.key(&a.fee_payer_keypair_path, &a.fee_payer_key_ref, "accounts", "fee_payer", prod);
+        v.key(&a.submitter_keypair_path, &a.submitter_key_ref, "accounts", "submitter", prod);
+        if let Some(r) = &a.registry_admin_ref {
+            v.key_ref(r, "accounts.registry_admin_ref");
+        }
+        if let Some(r) = &a.pause_guardian_ref {
+            v.key_ref(r, "accounts.pause_guardian_ref");
+        }
+
+        let st = &self.storage;
+        v.url(&st.postgres_url, "storage.postgres_url", &["postgres", "postgresql"]);
+        v.url(&st.redis_url, "storage.redis_url", &["redis", "rediss"]);
+        v.url(&st.clickhouse_url, "storage.clickhouse_url", &["http", "https"]);
+        match st.object_store {
+            ObjectStore::Localfs => {
+                let why = "object_store = \"localfs\"";
+                v.required(&st.object_store_root, "storage.object_store_root", why);
+                v.unused(&st.object_store_bucket, "storage.object_store_bucket", why);
+                v.unused(&st.object_store_prefix, "storage.object_store_prefix", why);
+                v.check(!prod, "storage.object_store", "localfs is not allowed on mainnet-beta");
+            }
+            ObjectStore::S3 => {
+                let why = "object_store = \"s3\"";
+                v.required(&st.object_store_bucket, "storage.object_store_bucket", why);
+                v.unused(&st.object_store_root, "storage.object_store_root", why);
+            }
+        }
+
+        let e = &self.eventlog;
+        v.check(e.max_inflight > 0, "eventlog.max_inflight", "must be > 0");
+        match e.driver {
+            EventlogDriver::Nats => {
+                let why = "driver = \"nats\"";
+                if let Some(url) = v.required(&e.url, "eventlog.url", why) {
+                    v.url(url, "eventlog.url", &["nats", "tls"]);
+                }
+                v.required(&e.subject_prefix, "eventlog.subject_prefix", why);
+                v.required(&e.durable_consumer, "eventlog.durable_consumer", why);
+                v.unused(&e.brokers, "eventlog.brokers", why);
+                v.unused(&e.topic_prefix, "eventlog.topic_prefix", why);
+                v.unused(&e.consumer_group, "eventlog.consumer_group", why);
+            }
+            EventlogDriver::Kafka => {
+                let why = "driver = \"kafka\"";
+                match &e.brokers {
+                    Some(b) => {
+                        v.check(!b.is_empty(), "eventlog.brokers", "must list at least one broker");
+                        for (i, broker) in b.iter().enumerate() {
+                            v.non_empty(broker, &format!("eventlog.brokers[{i}]"));
+                        }
+                    }
+                    None => v.0.push(format!("eventlog.brokers: required when {why}")),
+                }
+                v.required(&e.topic_prefix, "eventlog.topic_prefix", why);
+                v.required(&e.consumer_group, "eventlog.consumer_group", why);
+                v.unused(&e.url, "eventlog.url", why);
+                v.unused(&e.subject_prefix, "eventlog.subject_prefix", why);
+                v.unused(&e.durable_consumer, "eventlog.durable_consumer", why);
+            }
+        }
+
+        let en = &self.engine;
+        v.non_empty(&en.profile, "engine.profile");
+        v.check(en.tick_interval_ms > 0, "engine.tick_interval_ms", "must be > 0");
+        v.check(en.max_concurrent_markets > 0, "engine.max_concurrent_markets", "must be > 0");
+        v.check(en.max_inflight_events > 0, "engine.max_inflight_events", "must be > 0");
+        v.check(en.feature_window_seconds > 0, "engine.feature_window_seconds", "must be > 0");
+        v.check(en.bundle_max_markets > 0, "engine.bundle_max_markets", "must be > 0");
+        v.check(
+            en.bundle_max_markets <= en.max_concurrent_markets,
+            "engine.bundle_max_markets",
+            "must be <= engine.max_concurrent_markets",
+        );
+
+        v.non_empty(&en.normalization.default_timezone, "engine.normalization.default_timezone");
+
+        let m = &en.models;
+        v.non_empty(&m.model_family, "engine.models.model_family");
+        if m.calibration_enabled {
+            v.non_empty(&m.calibration_artifact_path, "engine.models.calibration_artifact_path");
+        }
+        v.check(m.prior_strength.is_finite() && m.prior_strength > 0.0, "engine.models.prior_strength", "must be a finite number > 0");
+        v.check(m.min_observations > 0, "engine.models.min_observations", "must be > 0");
+        v.check(m.max_features > 0, "engine.models.max_features", "must be > 0");
+
+        let g = &en.guardrails;
+        v.check(g.max_jump_bps <= MAX_BPS, "engine.guardrails.max_jump_bps", format_args!("must be <= {MAX_BPS}"));
+        v.check(
+            g.max_ci_width_bps > 0 && g.max_ci_width_bps <= MAX_BPS,
+            "engine.guardrails.max_ci_width_bps",
+            format_args!("must be in 1..={MAX_BPS}"),
+        );
+        v.check(
+            (0.0..=1.0).contains(&g.min_source_coverage_ratio),
+            "engine.guardrails.min_source_coverage_ratio",
+            "must be in 0.0..=1.0",
+        );
+        v.check(g.max_staleness_ms > 0, "engine.guardrails.max_staleness_ms", "must be > 0");
+
+        let p = &self.publish;
+        v.check(
+            p.commit_reveal == (p.mode == PublishMode::CommitReveal),
+            "publish.commit_reveal",
+            "must be true exactly when mode = \"commit_reveal\"",
+        );
+        v.check(p.cadence_ms > 0, "publish.cadence_ms", "must be > 0");
+        v.check(p.concurrency > 0, "publish.concurrency", "must be > 0");
+        v.check(
+            p.compute_unit_limit > 0 && p.compute_unit_limit <= MAX_COMPUTE_UNITS,
+            "publish.compute_unit_limit",
+            format_args!("must be in 1..={MAX_COMPUTE_UNITS}"),
+        );
+        v.check(
+            p.mode != PublishMode::CommitReveal || p.reveal_delay_ms > 0,
+            "publish.reveal_delay_ms",
+            "must be > 0 in commit_reveal mode",
+        );
+        v.non_empty(&p.idempotency_store, "publish.idempotency_store");
+
+        let sg = &self.signers;
+        let endpoints = sg.signer_agent_endpoints.len();
+        v.check(
+            sg.threshold > 0 && usize::from(sg.threshold) <= endpoints,
+            "signers.threshold",
+            format_args!("must be in 1..={endpoints} (number of signer_agent_endpoints)"),
+        );
+        for (i, url) in sg.signer_agent_endpoints.iter().enumerate() {
+            v.url(url, &format!("signers.signer_agent_endpoints[{i}]"), &["http", "https"]);
+        }
+        v.check(sg.request_timeout_ms > 0, "signers.request_timeout_ms", "must be > 0");
+        v.check(!prod || sg.key_source == KeySource::Kms, "signers.key_source", "must be \"kms\" on mainnet-beta");
+
+        let api = &self.api;
+        v.check(api.http_bind.parse::<SocketAddr>().is_ok(), "api.http_bind", format_args!("expected host:port, got `{}`", api.http_bind));
+        v.check(api.ws_bind.parse::<SocketAddr>().is_ok(), "api.ws_bind", format_args!("expected host:port, got `{}`", api.ws_bind));
+        v.check(!api.cors_allowed_origins.is_empty(), "api.cors_allowed_origins", "must not be empty");
+        v.check(
+            !prod || !api.cors_allowed_origins.iter().any(|o| o == "*"),
+            "api.cors_allowed_origins",
+            "wildcard origin is not allowed on mainnet-beta",
+        );
+        v.check(api.max_request_body_bytes > 0, "api.max_request_body_bytes", "must be > 0");
+        v.check(api.rate_limit_rps > 0, "api.rate_limit_rps", "must be > 0");
+        v.check(api.rate_limit_burst >= api.rate_limit_rps, "api.rate_limit_burst", "must be >= api.rate_limit_rps");
+
+        let sec = &self.security;
+        v.check(!sec.mtls_enabled || sec.require_tls, "security.mTLS_enabled", "requires security.require_tls");
+        v.check(!prod || sec.require_tls, "security.require_tls", "must be true on mainnet-beta");
+        v.check(
+            !(prod && sec.admin_token.is_some()),
+            "security.admin_token",
+            "inline tokens are not allowed on mainnet-beta; use admin_token_ref",
+        );
+        match (&sec.admin_token, &sec.admin_token_ref) {
+            (Some(_), Some(_)) => v.0.push("security: set only one of `admin_token` and `admin_token_ref`".into()),
+            (None, None) if sec.admin_api_enabled => {
+                v.0.push("security: `admin_token` or `admin_token_ref` is required when admin_api_enabled = true".into())
+            }
+            (Some(t), None) => v.non_empty(t, "security.admin_token"),
+            (None, Some(r)) => v.key_ref(r, "security.admin_token_ref"),
+            (None, None) => {}
+        }
+
+        v.non_empty(&self.paths.markets_config_dir, "paths.markets_config_dir");
+        v.non_empty(&self.paths.risk_config_dir, "paths.risk_config_dir");
+        v.non_empty(&self.paths.telemetry_dir, "paths.telemetry_dir");
+
+        v.into_result()
+    }
+}
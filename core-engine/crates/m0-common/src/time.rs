@@ -9,3 +9,50 @@ pub fn now_ms() -> u64 {
     let dt = now_utc();
     dt.timestamp_millis().max(0) as u64
 }
+
+// Epoch/tick rounding shared with m0-oracle (docs/protocol-spec/epoch-rounding.md).
+// Windows are `[window_start_ms, window_end_ms)`; the on-chain epoch_id is the index.
+pub const EPOCH_ORIGIN_MS: u64 = 0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EpochWindow {
+    pub epoch_id: u64,
+    pub window_start_ms: u64,
+    pub window_end_ms: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tick {
+    pub tick_index: u32,
+    pub tick_start_ms: u64,
+    pub tick_end_ms: u64,
+}
+
+pub fn epoch_window_at(t_ms: u64, epoch_window_ms: u64) -> EpochWindow {
+    let window = epoch_window_ms.max(1);
+    let epoch_id = t_ms.saturating_sub(EPOCH_ORIGIN_MS) / window;
+    epoch_window_of(epoch_id, window)
+}
+
+pub fn epoch_window_of(epoch_id: u64, epoch_window_ms: u64) -> EpochWindow {
+    let window_start_ms = EPOCH_ORIGIN_MS.saturating_add(epoch_id.saturating_mul(epoch_window_ms));
+    EpochWindow { epoch_id, window_start_ms, window_end_ms: window_start_ms.saturating_add(epoch_window_ms) }
+}
+
+/// Clamps `t_ms` into the window, then rounds down to the cadence grid (spec §10).
+pub fn tick_at(t_ms: u64, window: &EpochWindow, publish_cadence_ms: u64) -> Tick {
+    let cadence = publish_cadence_ms.max(1);
+    let t = t_ms.clamp(window.window_start_ms, window.window_end_ms.saturating_sub(1));
+    let tick_index = (t - window.window_start_ms) / cadence;
+    let tick_start_ms = window.window_start_ms + tick_index * cadence;
+    Tick {
+        tick_index: tick_index as u32,
+        tick_start_ms,
+        tick_end_ms: (tick_start_ms + cadence).min(window.window_end_ms),
+    }
+}
+
+/// Epoch the chain considers current for a market.
+pub fn current_epoch(epoch_window_ms: u64) -> EpochWindow {
+    epoch_window_at(now_ms(), epoch_window_ms)
+}
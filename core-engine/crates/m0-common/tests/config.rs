@@ -0,0 +1,134 @@
+use m0_common::config::{Cluster, Config, EventlogDriver, KeySource, ObjectStore, PolicyLevel};
+use m0_common::M0Error;
+
+fn read(env: &str) -> String {
+    let path = format!("{}/../../../config/{env}.toml", env!("CARGO_MANIFEST_DIR"));
+    std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{path}: {e}"))
+}
+
+fn no_env() -> Vec<(String, String)> {
+    Vec::new()
+}
+
+fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+}
+
+fn set(overrides: &[&str]) -> Vec<String> {
+    overrides.iter().map(|s| s.to_string()).collect()
+}
+
+fn err(src: &str, env: Vec<(String, String)>, cli: &[&str]) -> String {
+    match Config::from_layers(src, env, &set(cli)) {
+        Ok(_) => panic!("expected an error"),
+        Err(e) => e.to_string(),
+    }
+}
+
+#[test]
+fn shipped_configs_load() {
+    let dev = Config::from_layers(&read("dev"), no_env(), &[]).unwrap();
+    assert_eq!(dev.env.name, "dev");
+    assert_eq!(dev.solana.cluster, Cluster::Localnet);
+    assert_eq!(dev.eventlog.driver, EventlogDriver::Nats);
+    assert_eq!(dev.storage.object_store, ObjectStore::Localfs);
+    assert_eq!(dev.engine.guardrails.policy_level, PolicyLevel::Normal);
+    assert!(!dev.security.mtls_enabled);
+
+    let staging = Config::from_layers(&read("staging"), no_env(), &[]).unwrap();
+    assert_eq!(staging.eventlog.driver, EventlogDriver::Kafka);
+    assert_eq!(staging.signers.key_source, KeySource::Kms);
+    assert!(staging.security.mtls_enabled);
+
+    let prod = Config::from_layers(&read("prod"), no_env(), &[]).unwrap();
+    assert_eq!(prod.solana.cluster, Cluster::MainnetBeta);
+    assert_eq!(prod.signers.threshold, 5);
+    assert_eq!(prod.accounts.pause_guardian_ref.as_deref(), Some("multisig://prod/pause-guardian"));
+}
+
+#[test]
+fn env_then_cli_overrides() {
+    let env = vars(&[
+        ("M0_ENGINE__GUARDRAILS__MAX_JUMP_BPS", "900"),
+        ("M0_SECURITY__MTLS_ENABLED", "false"),
+        ("M0_API__CORS_ALLOWED_ORIGINS", "https://a.example, https://b.example"),
+        ("M0_ENGINE__TICK_INTERVAL_MS", "500"),
+        // Not a config override: no `__` separator.
+        ("M0_LOG_JSON", "1"),
+    ]);
+    let cfg = Config::from_layers(&read("dev"), env, &set(&["engine.tick_interval_ms=750"])).unwrap();
+
+    assert_eq!(cfg.engine.guardrails.max_jump_bps, 900);
+    assert!(!cfg.security.mtls_enabled);
+    assert_eq!(cfg.api.cors_allowed_origins, vec!["https://a.example", "https://b.example"]);
+    // CLI wins over env.
+    assert_eq!(cfg.engine.tick_interval_ms, 750);
+}
+
+#[test]
+fn override_type_errors_name_the_key() {
+    let e = err(&read("dev"), vars(&[("M0_ENGINE__TICK_INTERVAL_MS", "fast")]), &[]);
+    assert!(e.contains("M0_ENGINE__TICK_INTERVAL_MS: engine.tick_interval_ms: expected an integer"), "{e}");
+
+    let e = err(&read("dev"), no_env(), &["engine.guardrails=1"]);
+    assert!(e.contains("engine.guardrails: is a table"), "{e}");
+
+    let e = err(&read("dev"), no_env(), &["engine.tick_interval_ms"]);
+    assert!(e.contains("expected KEY=VALUE"), "{e}");
+}
+
+#[test]
+fn deserialize_errors_name_the_section() {
+    let e = err(&read("dev"), no_env(), &["engine.guardrails.policy_level=LOOSE"]);
+    assert!(e.starts_with("configuration error: engine.guardrails: unknown variant `LOOSE`"), "{e}");
+
+    let e = err(&read("dev"), no_env(), &["engine.models.typo=1"]);
+    assert!(e.contains("engine.models: unknown field `typo`"), "{e}");
+
+    let e = err(&read("dev"), no_env(), &["telemetry.enabled=true"]);
+    assert!(e.contains("telemetry: unknown section"), "{e}");
+
+    let src = read("dev").replace("[publish]", "[publish_old]");
+    let e = err(&src, no_env(), &[]);
+    assert!(e.contains("publish: missing section"), "{e}");
+}
+
+#[test]
+fn validation_reports_every_issue_with_its_path() {
+    let res = Config::from_layers(
+        &read("dev"),
+        no_env(),
+        &set(&[
+            "engine.guardrails.max_jump_bps=20000",
+            "signers.threshold=4",
+            "eventlog.brokers=[\"k:9092\"]",
+        ]),
+    );
+    let msg = match res {
+        Err(M0Error::Validation(msg)) => msg,
+        other => panic!("expected a validation error, got {other:?}"),
+    };
+    assert!(msg.contains("engine.guardrails.max_jump_bps: must be <= 10000"), "{msg}");
+    assert!(msg.contains("signers.threshold: must be in 1..=3"), "{msg}");
+    assert!(msg.contains("eventlog.brokers: not allowed when driver = \"nats\""), "{msg}");
+}
+
+#[test]
+fn mainnet_rejects_dev_secrets() {
+    let e = err(&read("dev"), vars(&[("M0_SOLANA__CLUSTER", "mainnet-beta")]), &[]);
+    for path in [
+        "accounts.fee_payer_keypair_path",
+        "signers.key_source",
+        "security.require_tls",
+        "security.admin_token",
+        "api.cors_allowed_origins",
+    ] {
+        assert!(e.contains(path), "{path} missing from: {e}");
+    }
+}
+
+#[test]
+fn missing_file_is_an_io_error() {
+    let res = Config::load("does/not/exist.toml", &[]);
+    assert!(matches!(res, Err(M0Error::Io(_))), "{res:?}");
+}
@@ -0,0 +1,18 @@
+use m0_common::time::{epoch_window_at, epoch_window_of, tick_at};
+
+#[test]
+fn epoch_bounds_are_half_open() {
+    let w = epoch_window_at(7_199_999, 3_600_000);
+    assert_eq!((w.epoch_id, w.window_start_ms, w.window_end_ms), (1, 3_600_000, 7_200_000));
+    assert_eq!(epoch_window_at(7_200_000, 3_600_000).epoch_id, 2);
+    assert_eq!(epoch_window_of(2, 3_600_000).window_start_ms, 7_200_000);
+}
+
+#[test]
+fn ticks_clamp_to_window() {
+    let w = epoch_window_of(1, 3_600_000);
+    let t = tick_at(3_600_000 + 301_000, &w, 300_000);
+    assert_eq!((t.tick_index, t.tick_start_ms, t.tick_end_ms), (1, 3_900_000, 4_200_000));
+    assert_eq!(tick_at(0, &w, 300_000).tick_index, 0);
+    assert_eq!(tick_at(u64::MAX, &w, 300_000).tick_index, 11);
+}
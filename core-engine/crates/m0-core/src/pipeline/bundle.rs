@@ -1,6 +1,6 @@
 
 use m0_bundle::format::{Bundle, MarketReveal, OutcomePoint};
-use m0_bundle::codec::encode_json;
+use m0_bundle::codec::encode_canonical;
 use m0_bundle::hashing::bundle_content_hash;
 use m0_common::ids::BundleId;
 use m0_common::time::now_ms;
@@ -36,7 +36,7 @@ pub fn build_bundle(schema_version: u16, signer_set_id: u64, publish_epoch_id: u
         markets: vec![mr],
     };
 
-    let bytes = encode_json(&b)?;
+    let bytes = encode_canonical(&b);
     let h = bundle_content_hash(&bytes);
     Ok((b, bytes, h))
 }
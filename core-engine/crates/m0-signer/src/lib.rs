@@ -4,4 +4,5 @@ pub mod error;
 pub mod keyring;
 pub mod reveal;
 pub mod replay_protection;
+pub mod resolution;
 pub mod tx_submit;
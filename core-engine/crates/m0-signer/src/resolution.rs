@@ -0,0 +1,19 @@
+use sha2::{Digest, Sha256};
+
+/// Message for m0-oracle `resolve_market`. `winning_outcome_index` is an index into the
+/// market's outcomes; `None` resolves the market as void.
+pub fn resolution_message(market_id: &str, winning_outcome_index: Option<u8>, resolution_source_hash: &[u8; 32], signer_set_id: u64) -> [u8; 32] {
+    // Borsh encoding of ResolutionOutcome { Winner { outcome_index }, Void }
+    let outcome: Vec<u8> = match winning_outcome_index {
+        Some(i) => vec![0, i],
+        None => vec![1],
+    };
+    let mut h = Sha256::new();
+    h.update(b"M0_RESOLUTION_V1");
+    h.update((market_id.len() as u32).to_le_bytes());
+    h.update(market_id.as_bytes());
+    h.update(&outcome);
+    h.update(resolution_source_hash);
+    h.update(signer_set_id.to_le_bytes());
+    h.finalize().into()
+}
@@ -1,12 +1,34 @@
-
 use sha2::{Digest, Sha256};
 
-pub fn signature_message(bundle_content_hash: &[u8; 32], signer_set_id: u64, publish_epoch_id: u64, sequence: u64) -> [u8; 32] {
+/// Message for m0-oracle `reveal_prediction` / `reveal_merkle_root`. `market` and `epoch`
+/// are the oracle Market and Epoch PDAs, so equal sequences in two markets of the same
+/// epoch window sign different messages.
+pub fn signature_message(bundle_content_hash: &[u8; 32], market: &[u8; 32], epoch: &[u8; 32], signer_set_id: u64, publish_epoch_id: u64, sequence: u64) -> [u8; 32] {
     let mut h = Sha256::new();
-    h.update(b"M0_SIGMSG_V1");
+    h.update(b"M0_SIGMSG_V2");
     h.update(bundle_content_hash);
+    h.update(market);
+    h.update(epoch);
     h.update(signer_set_id.to_le_bytes());
     h.update(publish_epoch_id.to_le_bytes());
     h.update(sequence.to_le_bytes());
     h.finalize().into()
 }
+
+/// Message for m0-oracle `reveal_batch`: one signature covers every market of the bundle.
+/// `targets` are each market's (Market PDA, Epoch PDA, next epoch sequence), in bundle
+/// market order.
+pub fn batch_signature_message(bundle_content_hash: &[u8; 32], signer_set_id: u64, publish_epoch_id: u64, targets: &[([u8; 32], [u8; 32], u64)]) -> [u8; 32] {
+    let mut h = Sha256::new();
+    h.update(b"M0_BATCH_SIGMSG_V2");
+    h.update(bundle_content_hash);
+    h.update(signer_set_id.to_le_bytes());
+    h.update(publish_epoch_id.to_le_bytes());
+    h.update((targets.len() as u32).to_le_bytes());
+    for (market, epoch, sequence) in targets {
+        h.update(market);
+        h.update(epoch);
+        h.update(sequence.to_le_bytes());
+    }
+    h.finalize().into()
+}
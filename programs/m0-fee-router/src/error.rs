@@ -9,4 +9,14 @@ pub enum M0FeeRouterError {
     InvalidParameter,
     #[msg("Insufficient funds")]
     InsufficientFunds,
+    #[msg("No pending authority transfer")]
+    NoPendingAuthority,
+    #[msg("No routes configured")]
+    NoRoutes,
+    #[msg("Route accounts do not match the configured routes")]
+    RouteAccountMismatch,
+    #[msg("Nothing to route")]
+    NothingToRoute,
+    #[msg("Nothing to claim")]
+    NothingToClaim,
 }
@@ -0,0 +1,79 @@
+
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub proposed_at_slot: u64,
+}
+
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub authority: Pubkey,
+    pub cancelled_pending_authority: Pubkey,
+    pub cancelled_at_slot: u64,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub accepted_at_slot: u64,
+}
+
+#[event]
+pub struct LedgerInitialized {
+    pub ledger: Pubkey,
+    pub mint: Pubkey, // SOL_LEDGER_MINT for native SOL
+    pub token_account: Pubkey,
+    pub created_at_slot: u64,
+}
+
+#[event]
+pub struct FeeDeposited {
+    pub ledger: Pubkey,
+    pub mint: Pubkey,
+    pub depositor: Pubkey,
+    pub amount: u64,
+    pub cumulative: u64,
+    pub deposited_at_slot: u64,
+}
+
+#[event]
+pub struct FeesAccrued {
+    pub ledger: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64, // balance found in the vault without a deposit
+    pub cumulative: u64,
+    pub routed_at_slot: u64,
+}
+
+#[event]
+pub struct AccrualSettled {
+    pub ledger: Pubkey,
+    pub destination: Pubkey,
+    pub old_bps: u16,
+    pub new_bps: u16,
+    pub settled: u64,
+    pub owed: u64,
+    pub settled_at_slot: u64,
+}
+
+#[event]
+pub struct RoutesSet {
+    pub router: Pubkey,
+    pub route_count: u8,
+    pub ledgers_settled: u16,
+    pub set_at_slot: u64,
+}
+
+#[event]
+pub struct FeesClaimed {
+    pub ledger: Pubkey,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub claimed_total: u64,
+    pub claimed_at_slot: u64,
+}
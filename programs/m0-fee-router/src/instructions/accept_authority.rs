@@ -0,0 +1,37 @@
+
+use anchor_lang::prelude::*;
+use crate::error::M0FeeRouterError;
+use crate::events::*;
+use crate::state::router::{Router, ROUTER_SEED};
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ROUTER_SEED],
+        bump = router.bump
+    )]
+    pub router: Account<'info, Router>,
+}
+
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let r = &mut ctx.accounts.router;
+    let signer = ctx.accounts.new_authority.key();
+    match r.pending_authority {
+        None => return err!(M0FeeRouterError::NoPendingAuthority),
+        Some(p) if p != signer => return err!(M0FeeRouterError::Unauthorized),
+        Some(_) => {}
+    }
+
+    let previous = r.authority;
+    r.authority = signer;
+    r.pending_authority = None;
+    emit!(AuthorityTransferred {
+        previous_authority: previous,
+        new_authority: signer,
+        accepted_at_slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
@@ -0,0 +1,29 @@
+
+use anchor_lang::prelude::*;
+use crate::error::M0FeeRouterError;
+use crate::events::*;
+use crate::state::router::{Router, ROUTER_SEED};
+
+#[derive(Accounts)]
+pub struct CancelAuthorityTransfer<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ROUTER_SEED],
+        bump = router.bump,
+        has_one = authority @ M0FeeRouterError::Unauthorized
+    )]
+    pub router: Account<'info, Router>,
+}
+
+pub fn handler(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+    let r = &mut ctx.accounts.router;
+    let pending = r.pending_authority.take().ok_or(M0FeeRouterError::NoPendingAuthority)?;
+    emit!(AuthorityTransferCancelled {
+        authority: r.authority,
+        cancelled_pending_authority: pending,
+        cancelled_at_slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::error::M0FeeRouterError;
+use crate::events::*;
+use crate::state::accrual::{Accrual, ACCRUAL_SEED};
+use crate::state::ledger::{FeeLedger, LEDGER_SEED};
+use crate::state::router::{bps_of, Router, ROUTER_SEED};
+use crate::state::vaults::{FeeVault, VAULT_SEED};
+
+// Pays out a destination's accrued share of one ledger. Each destination claims on its
+// own, so a missing or frozen recipient doesn't block the others.
+
+#[derive(Accounts)]
+pub struct Claim<'info> {
+    #[account(mut)]
+    pub destination: Signer<'info>,
+
+    #[account(
+        seeds = [ROUTER_SEED],
+        bump = router.bump
+    )]
+    pub router: Account<'info, Router>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, FeeVault>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, ledger.mint.as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, FeeLedger>,
+
+    #[account(
+        init_if_needed,
+        payer = destination,
+        space = Accrual::LEN,
+        seeds = [ACCRUAL_SEED, ledger.key().as_ref(), destination.key().as_ref()],
+        bump
+    )]
+    pub accrual: Account<'info, Accrual>,
+
+    // SPL ledgers only: any token account of the destination for the ledger mint.
+    #[account(
+        mut,
+        constraint = destination_token.mint == ledger.mint @ M0FeeRouterError::InvalidParameter,
+        constraint = destination_token.owner == destination.key() @ M0FeeRouterError::InvalidParameter
+    )]
+    pub destination_token: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = ledger.token_account @ M0FeeRouterError::InvalidParameter
+    )]
+    pub vault_token: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<Claim>) -> Result<()> {
+    let destination = ctx.accounts.destination.key();
+    let ledger_key = ctx.accounts.ledger.key();
+    let cumulative = ctx.accounts.ledger.cumulative;
+
+    let a = &mut ctx.accounts.accrual;
+    if !a.is_initialized() {
+        // Untouched by set_routes since the ledger was created: current route from 0.
+        a.ledger = ledger_key;
+        a.destination = destination;
+        a.bps = bps_of(&ctx.accounts.router.routes, &destination);
+        a.checkpoint = 0;
+        a.bump = *ctx.bumps.get("accrual").unwrap();
+    }
+    a.settle(cumulative);
+    let amount = a.owed;
+    if amount == 0 {
+        return err!(M0FeeRouterError::NothingToClaim);
+    }
+    a.owed = 0;
+    a.claimed = a.claimed.saturating_add(amount);
+
+    if ctx.accounts.ledger.is_native() {
+        let vault = ctx.accounts.vault.to_account_info();
+        let rent_min = Rent::get()?.minimum_balance(vault.data_len());
+        if vault.lamports().saturating_sub(rent_min) < amount {
+            return err!(M0FeeRouterError::InsufficientFunds);
+        }
+        **vault.try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.destination.to_account_info().try_borrow_mut_lamports()? += amount;
+    } else {
+        let (Some(to), Some(from), Some(token_program)) = (&ctx.accounts.destination_token, &ctx.accounts.vault_token, &ctx.accounts.token_program) else {
+            return err!(M0FeeRouterError::InvalidParameter);
+        };
+        let bump = ctx.accounts.vault.bump;
+        let signer_seeds: &[&[u8]] = &[VAULT_SEED, &[bump]];
+        let accounts = Transfer {
+            from: from.to_account_info(),
+            to: to.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+        };
+        token::transfer(CpiContext::new_with_signer(token_program.to_account_info(), accounts, &[signer_seeds]), amount)?;
+    }
+
+    let l = &mut ctx.accounts.ledger;
+    l.claimed = l.claimed.saturating_add(amount);
+
+    emit!(FeesClaimed {
+        ledger: ledger_key,
+        mint: l.mint,
+        destination,
+        amount,
+        claimed_total: ctx.accounts.accrual.claimed,
+        claimed_at_slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
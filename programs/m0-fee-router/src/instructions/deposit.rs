@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::error::M0FeeRouterError;
+use crate::events::*;
+use crate::state::ledger::{FeeLedger, LEDGER_SEED};
+use crate::state::router::{Router, ROUTER_SEED};
+use crate::state::vaults::{FeeVault, VAULT_SEED};
+
+// Moves fees into the vault and credits the ledger, so every route destination's
+// share accrues at deposit time. Called by m0-oracle for publish fees.
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        seeds = [ROUTER_SEED],
+        bump = router.bump
+    )]
+    pub router: Account<'info, Router>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, FeeVault>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, ledger.mint.as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, FeeLedger>,
+
+    // SPL ledgers only.
+    #[account(
+        mut,
+        constraint = depositor_token.mint == ledger.mint @ M0FeeRouterError::InvalidParameter
+    )]
+    pub depositor_token: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = ledger.token_account @ M0FeeRouterError::InvalidParameter
+    )]
+    pub vault_token: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+    if amount == 0 {
+        return err!(M0FeeRouterError::InvalidParameter);
+    }
+    if ctx.accounts.router.routes.is_empty() {
+        return err!(M0FeeRouterError::NoRoutes);
+    }
+
+    if ctx.accounts.ledger.is_native() {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.depositor.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+    } else {
+        let (Some(from), Some(to), Some(token_program)) = (&ctx.accounts.depositor_token, &ctx.accounts.vault_token, &ctx.accounts.token_program) else {
+            return err!(M0FeeRouterError::InvalidParameter);
+        };
+        let accounts = Transfer {
+            from: from.to_account_info(),
+            to: to.to_account_info(),
+            authority: ctx.accounts.depositor.to_account_info(),
+        };
+        token::transfer(CpiContext::new(token_program.to_account_info(), accounts), amount)?;
+    }
+
+    let l = &mut ctx.accounts.ledger;
+    l.cumulative = l.cumulative.checked_add(amount).ok_or(M0FeeRouterError::InvalidParameter)?;
+
+    emit!(FeeDeposited {
+        ledger: l.key(),
+        mint: l.mint,
+        depositor: ctx.accounts.depositor.key(),
+        amount,
+        cumulative: l.cumulative,
+        deposited_at_slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
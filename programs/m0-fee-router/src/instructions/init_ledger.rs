@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::error::M0FeeRouterError;
+use crate::events::*;
+use crate::state::ledger::{FeeLedger, LEDGER_SEED, SOL_LEDGER_MINT};
+use crate::state::router::{Router, ROUTER_SEED};
+use crate::state::vaults::{FeeVault, VAULT_SEED};
+
+#[derive(Accounts)]
+#[instruction(mint: Pubkey)]
+pub struct InitLedger<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ROUTER_SEED],
+        bump = router.bump,
+        has_one = authority @ M0FeeRouterError::Unauthorized
+    )]
+    pub router: Account<'info, Router>,
+
+    #[account(
+        seeds = [VAULT_SEED],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, FeeVault>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = FeeLedger::LEN,
+        seeds = [LEDGER_SEED, mint.as_ref()],
+        bump
+    )]
+    pub ledger: Account<'info, FeeLedger>,
+
+    // Required for SPL ledgers: the vault-owned token account holding this mint.
+    pub vault_token: Option<Account<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitLedger>, mint: Pubkey) -> Result<()> {
+    let token_account = if mint == SOL_LEDGER_MINT {
+        Pubkey::default()
+    } else {
+        let t = ctx.accounts.vault_token.as_ref().ok_or(M0FeeRouterError::InvalidParameter)?;
+        if t.mint != mint || t.owner != ctx.accounts.vault.key() || t.delegate.is_some() || t.close_authority.is_some() {
+            return err!(M0FeeRouterError::InvalidParameter);
+        }
+        t.key()
+    };
+
+    let l = &mut ctx.accounts.ledger;
+    l.mint = mint;
+    l.token_account = token_account;
+    l.cumulative = 0;
+    l.claimed = 0;
+    l.bump = *ctx.bumps.get("ledger").unwrap();
+
+    let r = &mut ctx.accounts.router;
+    r.ledger_count = r.ledger_count.checked_add(1).ok_or(M0FeeRouterError::InvalidParameter)?;
+
+    emit!(LedgerInitialized {
+        ledger: l.key(),
+        mint,
+        token_account,
+        created_at_slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
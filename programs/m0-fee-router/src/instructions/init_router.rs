@@ -32,7 +32,9 @@ pub struct InitRouter<'info> {
 pub fn handler(ctx: Context<InitRouter>) -> Result<()> {
     let r = &mut ctx.accounts.router;
     r.authority = ctx.accounts.authority.key();
+    r.pending_authority = None;
     r.routes = vec![];
+    r.ledger_count = 0;
     r.bump = *ctx.bumps.get("router").unwrap();
 
     let v = &mut ctx.accounts.vault;
@@ -1,4 +1,9 @@
-
+pub mod accept_authority;
+pub mod cancel_authority_transfer;
+pub mod claim;
+pub mod deposit;
+pub mod init_ledger;
 pub mod init_router;
+pub mod propose_authority;
 pub mod route_fees;
 pub mod set_routes;
@@ -0,0 +1,32 @@
+
+use anchor_lang::prelude::*;
+use crate::error::M0FeeRouterError;
+use crate::events::*;
+use crate::state::router::{Router, ROUTER_SEED};
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ROUTER_SEED],
+        bump = router.bump,
+        has_one = authority @ M0FeeRouterError::Unauthorized
+    )]
+    pub router: Account<'info, Router>,
+}
+
+pub fn handler(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    let r = &mut ctx.accounts.router;
+    if new_authority == Pubkey::default() || new_authority == r.authority {
+        return err!(M0FeeRouterError::InvalidParameter);
+    }
+    r.pending_authority = Some(new_authority);
+    emit!(AuthorityTransferProposed {
+        authority: r.authority,
+        pending_authority: new_authority,
+        proposed_at_slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
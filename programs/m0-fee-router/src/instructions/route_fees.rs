@@ -1,12 +1,14 @@
-
 use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::error::M0FeeRouterError;
+use crate::events::*;
+use crate::state::ledger::{FeeLedger, LEDGER_SEED};
 use crate::state::router::{Router, ROUTER_SEED};
 use crate::state::vaults::{FeeVault, VAULT_SEED};
 
-// This instruction is a skeleton:
-// In production you would route SPL token fees from a vault ATA to destination ATAs.
-// For simplicity, this program stores routing config only.
-// Fee movement can be implemented by adding token accounts + CPI to token program.
+// Permissionless crank. Credits balance that reached the vault without `deposit`
+// (direct transfers, m0-slashing proceeds) to the ledger, so it is split across the
+// routes like any deposit. Destinations withdraw with `claim`.
 
 #[derive(Accounts)]
 pub struct RouteFees<'info> {
@@ -21,8 +23,45 @@ pub struct RouteFees<'info> {
         bump = vault.bump
     )]
     pub vault: Account<'info, FeeVault>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, ledger.mint.as_ref()],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, FeeLedger>,
+
+    // SPL ledgers only.
+    #[account(address = ledger.token_account @ M0FeeRouterError::InvalidParameter)]
+    pub vault_token: Option<Account<'info, TokenAccount>>,
 }
 
-pub fn handler(_ctx: Context<RouteFees>) -> Result<()> {
+pub fn handler(ctx: Context<RouteFees>) -> Result<()> {
+    if ctx.accounts.router.routes.is_empty() {
+        return err!(M0FeeRouterError::NoRoutes);
+    }
+
+    let balance = if ctx.accounts.ledger.is_native() {
+        let info = ctx.accounts.vault.to_account_info();
+        let rent_min = Rent::get()?.minimum_balance(info.data_len());
+        info.lamports().saturating_sub(rent_min)
+    } else {
+        ctx.accounts.vault_token.as_ref().ok_or(M0FeeRouterError::InvalidParameter)?.amount
+    };
+
+    let l = &mut ctx.accounts.ledger;
+    let untracked = balance.saturating_sub(l.outstanding());
+    if untracked == 0 {
+        return err!(M0FeeRouterError::NothingToRoute);
+    }
+    l.cumulative = l.cumulative.checked_add(untracked).ok_or(M0FeeRouterError::InvalidParameter)?;
+
+    emit!(FeesAccrued {
+        ledger: l.key(),
+        mint: l.mint,
+        amount: untracked,
+        cumulative: l.cumulative,
+        routed_at_slot: Clock::get()?.slot,
+    });
     Ok(())
 }
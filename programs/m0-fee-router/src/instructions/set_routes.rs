@@ -1,29 +1,103 @@
-
 use anchor_lang::prelude::*;
 use crate::error::M0FeeRouterError;
-use crate::state::router::{Router, Route, ROUTER_SEED};
+use crate::events::*;
+use crate::state::accrual::{Accrual, ACCRUAL_SEED};
+use crate::state::ledger::{FeeLedger, LEDGER_SEED};
+use crate::state::router::{bps_of, Router, Route, ROUTER_SEED};
+use crate::utils::accounts::{load, load_or_create, require_pda, store};
+
+// Accruals are settled at the old bps before the new routes apply. remaining_accounts
+// holds one group per FeeLedger (Router.ledger_count): [ledger, accrual...], with one
+// accrual per destination in the old routes (in order) followed by destinations that
+// are only in the new routes. Missing accruals are created, paid by the authority.
 
 #[derive(Accounts)]
+#[instruction(routes: Vec<Route>)]
 pub struct SetRoutes<'info> {
+    #[account(mut)]
     pub authority: Signer<'info>,
 
     #[account(
         mut,
         seeds = [ROUTER_SEED],
         bump = router.bump,
-        has_one = authority @ M0FeeRouterError::Unauthorized
+        has_one = authority @ M0FeeRouterError::Unauthorized,
+        realloc = Router::len_with(routes.len()),
+        realloc::payer = authority,
+        realloc::zero = false
     )]
     pub router: Account<'info, Router>,
+
+    pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<SetRoutes>, routes: Vec<Route>) -> Result<()> {
-    let mut sum: u32 = 0;
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, SetRoutes<'info>>, routes: Vec<Route>) -> Result<()> {
+    Router::validate_routes(&routes)?;
+
+    let old = ctx.accounts.router.routes.clone();
+    let mut members: Vec<Pubkey> = old.iter().map(|r| r.destination).collect();
     for r in &routes {
-        sum += r.bps as u32;
+        if !members.contains(&r.destination) {
+            members.push(r.destination);
+        }
     }
-    if sum != 10_000 {
-        return err!(M0FeeRouterError::InvalidParameter);
+
+    let group = 1 + members.len();
+    let groups = ctx.remaining_accounts;
+    if groups.len() != ctx.accounts.router.ledger_count as usize * group {
+        return err!(M0FeeRouterError::RouteAccountMismatch);
     }
-    ctx.accounts.router.routes = routes;
+
+    let authority = ctx.accounts.authority.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let now = Clock::get()?.slot;
+
+    for (i, g) in groups.chunks(group).enumerate() {
+        let ledger_info = &g[0];
+        if groups[..i * group].chunks(group).any(|p| p[0].key == ledger_info.key) {
+            return err!(M0FeeRouterError::RouteAccountMismatch);
+        }
+        let ledger: FeeLedger = load(ledger_info)?;
+        require_pda(ledger_info, &[LEDGER_SEED, ledger.mint.as_ref()])?;
+
+        for (destination, info) in members.iter().zip(&g[1..]) {
+            let seeds: &[&[u8]] = &[ACCRUAL_SEED, ledger_info.key.as_ref(), destination.as_ref()];
+            let (mut a, bump): (Accrual, u8) = load_or_create(&authority, info, &system_program, Accrual::LEN, seeds)?;
+            if !a.is_initialized() {
+                a = Accrual {
+                    ledger: ledger_info.key(),
+                    destination: *destination,
+                    bps: bps_of(&old, destination),
+                    checkpoint: 0,
+                    owed: 0,
+                    claimed: 0,
+                    bump,
+                };
+            }
+            let old_bps = a.bps;
+            let settled = a.settle(ledger.cumulative);
+            a.bps = bps_of(&routes, destination);
+            store(info, &a)?;
+
+            emit!(AccrualSettled {
+                ledger: ledger_info.key(),
+                destination: *destination,
+                old_bps,
+                new_bps: a.bps,
+                settled,
+                owed: a.owed,
+                settled_at_slot: now,
+            });
+        }
+    }
+
+    let r = &mut ctx.accounts.router;
+    r.routes = routes;
+    emit!(RoutesSet {
+        router: r.key(),
+        route_count: r.routes.len() as u8,
+        ledgers_settled: r.ledger_count,
+        set_at_slot: now,
+    });
     Ok(())
 }
@@ -2,8 +2,10 @@
 use anchor_lang::prelude::*;
 
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 use instructions::*;
 
@@ -17,11 +19,35 @@ pub mod m0_fee_router {
         init_router::handler(ctx)
     }
 
-    pub fn set_routes(ctx: Context<set_routes::SetRoutes>, routes: Vec<state::router::Route>) -> Result<()> {
+    pub fn init_ledger(ctx: Context<init_ledger::InitLedger>, mint: Pubkey) -> Result<()> {
+        init_ledger::handler(ctx, mint)
+    }
+
+    pub fn set_routes<'info>(ctx: Context<'_, '_, 'info, 'info, set_routes::SetRoutes<'info>>, routes: Vec<state::router::Route>) -> Result<()> {
         set_routes::handler(ctx, routes)
     }
 
+    pub fn deposit(ctx: Context<deposit::Deposit>, amount: u64) -> Result<()> {
+        deposit::handler(ctx, amount)
+    }
+
     pub fn route_fees(ctx: Context<route_fees::RouteFees>) -> Result<()> {
         route_fees::handler(ctx)
     }
+
+    pub fn claim(ctx: Context<claim::Claim>) -> Result<()> {
+        claim::handler(ctx)
+    }
+
+    pub fn propose_authority(ctx: Context<propose_authority::ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        propose_authority::handler(ctx, new_authority)
+    }
+
+    pub fn accept_authority(ctx: Context<accept_authority::AcceptAuthority>) -> Result<()> {
+        accept_authority::handler(ctx)
+    }
+
+    pub fn cancel_authority_transfer(ctx: Context<cancel_authority_transfer::CancelAuthorityTransfer>) -> Result<()> {
+        cancel_authority_transfer::handler(ctx)
+    }
 }
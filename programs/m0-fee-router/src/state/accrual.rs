@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+pub const ACCRUAL_SEED: &[u8] = b"accrual";
+
+// Per-destination balance for one ledger, [ACCRUAL_SEED, ledger, destination].
+// A missing account means the destination has held its current route since the
+// ledger was created (set_routes creates accounts for every destination it touches).
+#[account]
+#[derive(Default)]
+pub struct Accrual {
+    pub ledger: Pubkey,
+    pub destination: Pubkey,
+    pub bps: u16,         // share earned from `checkpoint` on; 0 once the route is removed
+    pub checkpoint: u64,  // ledger.cumulative at the last settlement
+    pub owed: u64,        // settled, not yet claimed
+    pub claimed: u64,     // lifetime total claimed
+    pub bump: u8,
+}
+
+impl Accrual {
+    pub const LEN: usize = 8 + 32 + 32 + 2 + 8 + 8 + 8 + 1;
+
+    pub fn is_initialized(&self) -> bool {
+        self.ledger != Pubkey::default()
+    }
+
+    /// Credits this destination's share of the ledger growth since the checkpoint,
+    /// rounded down, and returns the credited amount.
+    pub fn settle(&mut self, cumulative: u64) -> u64 {
+        let delta = cumulative.saturating_sub(self.checkpoint);
+        let share = ((delta as u128) * (self.bps as u128) / 10_000u128) as u64;
+        self.owed = self.owed.saturating_add(share);
+        self.checkpoint = cumulative;
+        share
+    }
+}
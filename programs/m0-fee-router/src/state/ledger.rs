@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+pub const LEDGER_SEED: &[u8] = b"ledger";
+
+// Ledger key for native SOL, held as lamports on the FeeVault PDA itself.
+pub const SOL_LEDGER_MINT: Pubkey = Pubkey::new_from_array([0u8; 32]);
+
+// One per fee currency, [LEDGER_SEED, mint]. `cumulative` only grows; each Accrual
+// earns its bps of the growth since its checkpoint.
+#[account]
+pub struct FeeLedger {
+    pub mint: Pubkey,          // SOL_LEDGER_MINT for native SOL
+    pub token_account: Pubkey, // vault-owned token account; default for SOL
+    pub cumulative: u64,       // total credited (deposits + swept balances)
+    pub claimed: u64,          // total paid out by claim
+    pub bump: u8,
+}
+
+impl FeeLedger {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 1;
+
+    pub fn is_native(&self) -> bool {
+        self.mint == SOL_LEDGER_MINT
+    }
+
+    // Credited but not yet claimed, including rounding dust.
+    pub fn outstanding(&self) -> u64 {
+        self.cumulative.saturating_sub(self.claimed)
+    }
+}
@@ -1,3 +1,4 @@
-
+pub mod accrual;
+pub mod ledger;
 pub mod router;
 pub mod vaults;
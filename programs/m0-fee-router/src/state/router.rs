@@ -1,7 +1,8 @@
-
 use anchor_lang::prelude::*;
+use crate::error::M0FeeRouterError;
 
 pub const ROUTER_SEED: &[u8] = b"router";
+pub const MAX_ROUTES: usize = 16;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct Route {
@@ -12,13 +13,38 @@ pub struct Route {
 #[account]
 pub struct Router {
     pub authority: Pubkey,
+    pub pending_authority: Option<Pubkey>,
     pub routes: Vec<Route>,
+    pub ledger_count: u16, // FeeLedgers created; set_routes must settle all of them
     pub bump: u8,
 }
 
 impl Router {
     pub fn len_with(routes_len: usize) -> usize {
+        // pending_authority: 1 + 32
         // routes: 4 + routes_len * (32 + 2)
-        8 + 32 + 4 + routes_len * (32 + 2) + 1
+        // ledger_count: 2
+        8 + 32 + (1 + 32) + 4 + routes_len * (32 + 2) + 2 + 1
+    }
+
+    pub fn validate_routes(routes: &[Route]) -> Result<()> {
+        if routes.is_empty() || routes.len() > MAX_ROUTES {
+            return err!(M0FeeRouterError::InvalidParameter);
+        }
+        let mut sum: u32 = 0;
+        for (i, r) in routes.iter().enumerate() {
+            if r.bps == 0 || routes[..i].iter().any(|p| p.destination == r.destination) {
+                return err!(M0FeeRouterError::InvalidParameter);
+            }
+            sum += r.bps as u32;
+        }
+        if sum != 10_000 {
+            return err!(M0FeeRouterError::InvalidParameter);
+        }
+        Ok(())
     }
 }
+
+pub fn bps_of(routes: &[Route], destination: &Pubkey) -> u16 {
+    routes.iter().find(|r| &r.destination == destination).map(|r| r.bps).unwrap_or(0)
+}
@@ -13,4 +13,26 @@ pub enum M0GovernanceError {
     ProposalExecuted,
     #[msg("Voting closed")]
     VotingClosed,
+    #[msg("No pending authority transfer")]
+    NoPendingAuthority,
+    #[msg("Action accounts do not match the proposal")]
+    ActionAccountMismatch,
+    #[msg("No voting power")]
+    NoVotingPower,
+    #[msg("Stake is locked by an open vote")]
+    StakeLocked,
+    #[msg("Invalid proposal state for this action")]
+    InvalidProposalState,
+    #[msg("Voting still open")]
+    VotingOpen,
+    #[msg("Timelock not elapsed")]
+    TimelockNotElapsed,
+    #[msg("Proposal expired")]
+    ProposalExpired,
+    #[msg("Proposal not expired")]
+    ProposalNotExpired,
+    #[msg("Quorum not met")]
+    QuorumNotMet,
+    #[msg("Proposal not approved")]
+    NotApproved,
 }
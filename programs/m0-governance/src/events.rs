@@ -0,0 +1,136 @@
+
+use anchor_lang::prelude::*;
+use crate::state::governor::{ActionThreshold, ProposalState};
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub proposed_at_slot: u64,
+}
+
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub authority: Pubkey,
+    pub cancelled_pending_authority: Pubkey,
+    pub cancelled_at_slot: u64,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub accepted_at_slot: u64,
+}
+
+#[event]
+pub struct GuardiansSet {
+    pub governor: Pubkey,
+    pub guardians: Vec<Pubkey>,
+    pub set_at_slot: u64,
+}
+
+#[event]
+pub struct ThresholdsSet {
+    pub governor: Pubkey,
+    pub quorum_bps: u16,
+    pub approval_bps: u16,
+    pub action_thresholds: Vec<ActionThreshold>,
+    pub set_at_slot: u64,
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action_count: u8,
+    pub voting_supply: u64,
+    pub quorum_bps: u16,
+    pub approval_bps: u16,
+    pub voting_ends_at_slot: u64,
+}
+
+#[event]
+pub struct ProposalVoteFinalized {
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub state: ProposalState, // Succeeded or Defeated
+    pub yes_votes: u64,
+    pub no_votes: u64,
+    pub voting_supply: u64,
+    pub quorum_met: bool,
+    pub approved: bool,
+    pub finalized_at_slot: u64,
+}
+
+#[event]
+pub struct ProposalQueued {
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub eta_slot: u64,
+    pub expires_at_slot: u64,
+    pub queued_at_slot: u64,
+}
+
+#[event]
+pub struct ProposalCancelled {
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub cancelled_by: Pubkey,
+    pub by_guardian: bool,
+    pub previous_state: ProposalState,
+    pub cancelled_at_slot: u64,
+}
+
+#[event]
+pub struct ProposalExpired {
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub previous_state: ProposalState,
+    pub expired_at_slot: u64,
+}
+
+#[event]
+pub struct ActionExecuted {
+    pub proposal: Pubkey,
+    pub action_index: u8,
+    pub program_id: Pubkey,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub executor: Pubkey,
+    pub action_count: u8,
+    pub executed_at_slot: u64,
+}
+
+#[event]
+pub struct StakeDeposited {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub staked: u64,
+    pub total_staked: u64,
+}
+
+#[event]
+pub struct StakeWithdrawn {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub staked: u64,
+    pub total_staked: u64,
+}
+
+#[event]
+pub struct VoteCast {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub support: bool,
+    pub weight: u64,
+    pub changed: bool, // replaced an earlier vote by the same voter
+    pub yes_votes: u64,
+    pub no_votes: u64,
+    pub voted_at_slot: u64,
+}
@@ -0,0 +1,37 @@
+
+use anchor_lang::prelude::*;
+use crate::error::M0GovernanceError;
+use crate::events::*;
+use crate::state::governor::{Governor, GOVERNOR_SEED};
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNOR_SEED],
+        bump = governor.bump
+    )]
+    pub governor: Account<'info, Governor>,
+}
+
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let g = &mut ctx.accounts.governor;
+    let signer = ctx.accounts.new_authority.key();
+    match g.pending_authority {
+        None => return err!(M0GovernanceError::NoPendingAuthority),
+        Some(p) if p != signer => return err!(M0GovernanceError::Unauthorized),
+        Some(_) => {}
+    }
+
+    let previous = g.authority;
+    g.authority = signer;
+    g.pending_authority = None;
+    emit!(AuthorityTransferred {
+        previous_authority: previous,
+        new_authority: signer,
+        accepted_at_slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::error::M0GovernanceError;
+use crate::events::*;
+use crate::state::governor::{Governor, Proposal, ProposalState, GOVERNOR_SEED, PROPOSAL_SEED};
+
+// Guardians can cancel (veto) any proposal that hasn't reached a final state, including
+// one waiting in the timelock. The proposer can withdraw their own proposal while Active.
+
+#[derive(Accounts)]
+pub struct Cancel<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [GOVERNOR_SEED],
+        bump = governor.bump
+    )]
+    pub governor: Account<'info, Governor>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+pub fn handler(ctx: Context<Cancel>) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    let p = &mut ctx.accounts.proposal;
+
+    if p.is_final() {
+        return err!(M0GovernanceError::InvalidProposalState);
+    }
+    let by_guardian = ctx.accounts.governor.guardians.contains(&signer);
+    let by_proposer = signer == p.proposer && p.state == ProposalState::Active;
+    if !(by_guardian || by_proposer) {
+        return err!(M0GovernanceError::Unauthorized);
+    }
+
+    let previous_state = p.state;
+    p.state = ProposalState::Cancelled;
+
+    emit!(ProposalCancelled {
+        proposal: p.key(),
+        proposal_id: p.proposal_id,
+        cancelled_by: signer,
+        by_guardian,
+        previous_state,
+        cancelled_at_slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
@@ -0,0 +1,29 @@
+
+use anchor_lang::prelude::*;
+use crate::error::M0GovernanceError;
+use crate::events::*;
+use crate::state::governor::{Governor, GOVERNOR_SEED};
+
+#[derive(Accounts)]
+pub struct CancelAuthorityTransfer<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNOR_SEED],
+        bump = governor.bump,
+        has_one = authority @ M0GovernanceError::Unauthorized
+    )]
+    pub governor: Account<'info, Governor>,
+}
+
+pub fn handler(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+    let g = &mut ctx.accounts.governor;
+    let pending = g.pending_authority.take().ok_or(M0GovernanceError::NoPendingAuthority)?;
+    emit!(AuthorityTransferCancelled {
+        authority: g.authority,
+        cancelled_pending_authority: pending,
+        cancelled_at_slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::error::M0GovernanceError;
+use crate::events::*;
+use crate::state::governor::{Governor, GOVERNOR_SEED};
+use crate::state::stake::{StakeEscrow, STAKE_SEED};
+
+#[derive(Accounts)]
+pub struct DepositStake<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNOR_SEED],
+        bump = governor.bump
+    )]
+    pub governor: Account<'info, Governor>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = StakeEscrow::LEN,
+        seeds = [STAKE_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub escrow: Account<'info, StakeEscrow>,
+
+    #[account(
+        mut,
+        constraint = owner_token.mint == governor.governance_mint @ M0GovernanceError::InvalidParameter
+    )]
+    pub owner_token: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = governor.stake_vault @ M0GovernanceError::InvalidParameter
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<DepositStake>, amount: u64) -> Result<()> {
+    if amount == 0 {
+        return err!(M0GovernanceError::InvalidParameter);
+    }
+
+    let accounts = Transfer {
+        from: ctx.accounts.owner_token.to_account_info(),
+        to: ctx.accounts.stake_vault.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+    };
+    token::transfer(CpiContext::new(ctx.accounts.token_program.to_account_info(), accounts), amount)?;
+
+    let e = &mut ctx.accounts.escrow;
+    if e.owner == Pubkey::default() {
+        e.owner = ctx.accounts.owner.key();
+        e.bump = *ctx.bumps.get("escrow").unwrap();
+    }
+    e.record_deposit(amount, Clock::get()?.slot).ok_or(M0GovernanceError::InvalidParameter)?;
+
+    let g = &mut ctx.accounts.governor;
+    g.total_staked = g.total_staked.checked_add(amount).ok_or(M0GovernanceError::InvalidParameter)?;
+
+    emit!(StakeDeposited {
+        owner: e.owner,
+        amount,
+        staked: e.amount,
+        total_staked: g.total_staked,
+    });
+    Ok(())
+}
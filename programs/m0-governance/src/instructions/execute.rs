@@ -1,10 +1,16 @@
-
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
 use crate::error::M0GovernanceError;
-use crate::state::governor::{Proposal, PROPOSAL_SEED};
+use crate::events::*;
+use crate::state::governor::{Proposal, ProposalState, PROPOSAL_SEED};
 use crate::state::timelock::{Timelock, TIMELOCK_SEED};
 use crate::state::governor::{Governor, GOVERNOR_SEED};
 
+// remaining_accounts holds, per action in order: [program, accounts...] with the
+// accounts exactly as stored in the action. Each action is invoked with the governor
+// PDA as signer, so programs whose authority is the governor can be driven from here.
+
 #[derive(Accounts)]
 pub struct Execute<'info> {
     pub executor: Signer<'info>,
@@ -29,31 +35,75 @@ pub struct Execute<'info> {
     pub proposal: Account<'info, Proposal>,
 }
 
-pub fn handler(ctx: Context<Execute>) -> Result<()> {
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, Execute<'info>>) -> Result<()> {
     let now = Clock::get()?.slot;
     let g = &ctx.accounts.governor;
     let t = &ctx.accounts.timelock;
     let p = &mut ctx.accounts.proposal;
 
-    if p.executed {
-        return err!(M0GovernanceError::ProposalExecuted);
+    if p.state != ProposalState::Queued {
+        return err!(M0GovernanceError::InvalidProposalState);
     }
-
-    if now <= p.voting_ends_at_slot.saturating_add(t.min_delay_slots) {
-        return err!(M0GovernanceError::VotingClosed);
+    if now < p.eta_slot {
+        return err!(M0GovernanceError::TimelockNotElapsed);
     }
+    if now > p.expires_at_slot(t) {
+        return err!(M0GovernanceError::ProposalExpired);
+    }
+
+    // Marked before invoking so an action can't re-enter and execute it twice.
+    p.state = ProposalState::Executed;
 
-    // Quorum check (simple): yes_votes must be >= quorum bps of total votes.
-    let total = p.yes_votes.saturating_add(p.no_votes).max(1);
-    let yes_bps = (p.yes_votes.saturating_mul(10_000)) / total;
-    if yes_bps < g.quorum_bps as u64 {
-        return err!(M0GovernanceError::InvalidParameter);
+    let proposal_key = p.key();
+    let signer_seeds: &[&[u8]] = &[GOVERNOR_SEED, &[g.bump]];
+    let mut rest = ctx.remaining_accounts;
+
+    for (i, a) in p.actions.iter().enumerate() {
+        let n = 1 + a.accounts.len();
+        if rest.len() < n {
+            return err!(M0GovernanceError::ActionAccountMismatch);
+        }
+        let (group, tail) = rest.split_at(n);
+        rest = tail;
+
+        let program = &group[0];
+        if program.key() != a.program_id || !program.executable {
+            return err!(M0GovernanceError::ActionAccountMismatch);
+        }
+        for (m, info) in a.accounts.iter().zip(&group[1..]) {
+            if info.key() != m.pubkey {
+                return err!(M0GovernanceError::ActionAccountMismatch);
+            }
+        }
+
+        let ix = Instruction {
+            program_id: a.program_id,
+            accounts: a
+                .accounts
+                .iter()
+                .map(|m| AccountMeta { pubkey: m.pubkey, is_signer: m.is_signer, is_writable: m.is_writable })
+                .collect(),
+            data: a.data.clone(),
+        };
+        invoke_signed(&ix, group, &[signer_seeds])?;
+
+        emit!(ActionExecuted {
+            proposal: proposal_key,
+            action_index: i as u8,
+            program_id: a.program_id,
+        });
+    }
+    if !rest.is_empty() {
+        return err!(M0GovernanceError::ActionAccountMismatch);
     }
 
-    // Skeleton: in production, you would CPI invoke each action.
-    // Anchor CPI requires accounts; a generic executor is more complex.
-    // This program marks executed only.
-    p.executed = true;
+    emit!(ProposalExecuted {
+        proposal: proposal_key,
+        proposal_id: p.proposal_id,
+        executor: ctx.accounts.executor.key(),
+        action_count: p.actions.len() as u8,
+        executed_at_slot: now,
+    });
 
     Ok(())
 }
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::error::M0GovernanceError;
+use crate::events::*;
+use crate::state::governor::{Proposal, ProposalState, PROPOSAL_SEED};
+use crate::state::timelock::{Timelock, TIMELOCK_SEED};
+
+// Permissionless: a Succeeded proposal not queued, or a Queued one not executed, within
+// the grace period becomes Expired.
+
+#[derive(Accounts)]
+pub struct Expire<'info> {
+    #[account(
+        seeds = [TIMELOCK_SEED],
+        bump = timelock.bump
+    )]
+    pub timelock: Account<'info, Timelock>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+pub fn handler(ctx: Context<Expire>) -> Result<()> {
+    let now = Clock::get()?.slot;
+    let p = &mut ctx.accounts.proposal;
+
+    if !matches!(p.state, ProposalState::Succeeded | ProposalState::Queued) {
+        return err!(M0GovernanceError::InvalidProposalState);
+    }
+    if now <= p.expires_at_slot(&ctx.accounts.timelock) {
+        return err!(M0GovernanceError::ProposalNotExpired);
+    }
+
+    let previous_state = p.state;
+    p.state = ProposalState::Expired;
+
+    emit!(ProposalExpired {
+        proposal: p.key(),
+        proposal_id: p.proposal_id,
+        previous_state,
+        expired_at_slot: now,
+    });
+    Ok(())
+}
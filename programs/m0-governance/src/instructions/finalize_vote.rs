@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::error::M0GovernanceError;
+use crate::events::*;
+use crate::state::governor::{Proposal, ProposalState, PROPOSAL_SEED};
+
+// Permissionless once voting has ended: Active -> Succeeded | Defeated, against the
+// thresholds fixed when the proposal was created.
+
+#[derive(Accounts)]
+pub struct FinalizeVote<'info> {
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+pub fn handler(ctx: Context<FinalizeVote>) -> Result<()> {
+    let now = Clock::get()?.slot;
+    let p = &mut ctx.accounts.proposal;
+
+    if p.state != ProposalState::Active {
+        return err!(M0GovernanceError::InvalidProposalState);
+    }
+    if now <= p.voting_ends_at_slot {
+        return err!(M0GovernanceError::VotingOpen);
+    }
+
+    let quorum_met = p.quorum_met();
+    let approved = p.approved();
+    p.state = if quorum_met && approved { ProposalState::Succeeded } else { ProposalState::Defeated };
+
+    emit!(ProposalVoteFinalized {
+        proposal: p.key(),
+        proposal_id: p.proposal_id,
+        state: p.state,
+        yes_votes: p.yes_votes,
+        no_votes: p.no_votes,
+        voting_supply: p.voting_supply,
+        quorum_met,
+        approved,
+        finalized_at_slot: now,
+    });
+    Ok(())
+}
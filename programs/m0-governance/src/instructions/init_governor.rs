@@ -1,5 +1,7 @@
 
 use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+use crate::error::M0GovernanceError;
 use crate::state::governor::{Governor, GOVERNOR_SEED};
 use crate::state::timelock::{Timelock, TIMELOCK_SEED};
 
@@ -11,7 +13,7 @@ pub struct InitGovernor<'info> {
     #[account(
         init,
         payer = authority,
-        space = Governor::len_with(0),
+        space = Governor::len_with(0, 0),
         seeds = [GOVERNOR_SEED],
         bump
     )]
@@ -26,21 +28,38 @@ pub struct InitGovernor<'info> {
     )]
     pub timelock: Account<'info, Timelock>,
 
+    pub governance_mint: Account<'info, Mint>,
+
+    // Holds staked governance tokens; must be owned by the governor PDA.
+    #[account(
+        constraint = stake_vault.mint == governance_mint.key() @ M0GovernanceError::InvalidParameter,
+        constraint = stake_vault.owner == governor.key() @ M0GovernanceError::InvalidParameter,
+        constraint = stake_vault.delegate.is_none() && stake_vault.close_authority.is_none() @ M0GovernanceError::InvalidParameter
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<InitGovernor>, voting_period_slots: u64, quorum_bps: u16, min_delay_slots: u64) -> Result<()> {
+pub fn handler(ctx: Context<InitGovernor>, voting_period_slots: u64, quorum_bps: u16, approval_bps: u16, min_delay_slots: u64, grace_period_slots: u64) -> Result<()> {
     let g = &mut ctx.accounts.governor;
     g.authority = ctx.accounts.authority.key();
+    g.pending_authority = None;
     g.guardians = vec![];
+    g.governance_mint = ctx.accounts.governance_mint.key();
+    g.stake_vault = ctx.accounts.stake_vault.key();
+    g.total_staked = 0;
     g.voting_period_slots = voting_period_slots.max(1);
     g.quorum_bps = quorum_bps.min(10_000);
+    g.approval_bps = approval_bps.min(10_000);
+    g.action_thresholds = vec![];
     g.proposal_count = 0;
     g.bump = *ctx.bumps.get("governor").unwrap();
 
     let t = &mut ctx.accounts.timelock;
     t.governor = g.key();
     t.min_delay_slots = min_delay_slots;
+    t.grace_period_slots = grace_period_slots.max(1);
     t.bump = *ctx.bumps.get("timelock").unwrap();
 
     Ok(())
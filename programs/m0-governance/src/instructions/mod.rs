@@ -1,6 +1,16 @@
 
+pub mod accept_authority;
+pub mod cancel;
+pub mod cancel_authority_transfer;
+pub mod deposit_stake;
 pub mod execute;
+pub mod expire;
+pub mod finalize_vote;
 pub mod init_governor;
 pub mod propose;
+pub mod propose_authority;
+pub mod queue;
 pub mod set_guardians;
+pub mod set_thresholds;
 pub mod vote;
+pub mod withdraw_stake;
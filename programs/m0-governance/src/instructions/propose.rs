@@ -1,7 +1,8 @@
 
 use anchor_lang::prelude::*;
 use crate::error::M0GovernanceError;
-use crate::state::governor::{Governor, Proposal, Action, GOVERNOR_SEED, PROPOSAL_SEED};
+use crate::events::*;
+use crate::state::governor::{Governor, Proposal, ProposalState, Action, GOVERNOR_SEED, PROPOSAL_SEED};
 
 const MAX_ACTIONS: usize = 8;
 const MAX_ACTION_ACCOUNTS: usize = 16;
@@ -37,11 +38,26 @@ pub fn handler(ctx: Context<Propose>, actions: Vec<Action>) -> Result<()> {
     }
 
     let g = &mut ctx.accounts.governor;
+    // voting_supply is snapshotted below; with no stake nothing could ever vote.
+    if g.total_staked == 0 {
+        return err!(M0GovernanceError::NoVotingPower);
+    }
+    let governor_key = g.key();
+    for a in &actions {
+        if a.accounts.len() > MAX_ACTION_ACCOUNTS || a.data.len() > MAX_ACTION_DATA {
+            return err!(M0GovernanceError::InvalidParameter);
+        }
+        // Nobody else signs at execute time.
+        if a.accounts.iter().any(|m| m.is_signer && m.pubkey != governor_key) {
+            return err!(M0GovernanceError::InvalidParameter);
+        }
+    }
     let proposal_id = g.proposal_count.saturating_add(1);
     g.proposal_count = proposal_id;
 
     let now = Clock::get()?.slot;
     let ends = now.saturating_add(g.voting_period_slots);
+    let (quorum_bps, approval_bps) = g.thresholds_for(&actions);
 
     let p = &mut ctx.accounts.proposal;
     p.governor = g.key();
@@ -51,9 +67,25 @@ pub fn handler(ctx: Context<Propose>, actions: Vec<Action>) -> Result<()> {
     p.voting_ends_at_slot = ends;
     p.yes_votes = 0;
     p.no_votes = 0;
-    p.executed = false;
+    p.voting_supply = g.total_staked;
+    p.quorum_bps = quorum_bps;
+    p.approval_bps = approval_bps;
+    p.state = ProposalState::Active;
+    p.queued_at_slot = 0;
+    p.eta_slot = 0;
     p.actions = actions;
     p.bump = *ctx.bumps.get("proposal").unwrap();
 
+    emit!(ProposalCreated {
+        proposal: p.key(),
+        proposal_id,
+        proposer: p.proposer,
+        action_count: p.actions.len() as u8,
+        voting_supply: p.voting_supply,
+        quorum_bps,
+        approval_bps,
+        voting_ends_at_slot: ends,
+    });
+
     Ok(())
 }
@@ -0,0 +1,32 @@
+
+use anchor_lang::prelude::*;
+use crate::error::M0GovernanceError;
+use crate::events::*;
+use crate::state::governor::{Governor, GOVERNOR_SEED};
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNOR_SEED],
+        bump = governor.bump,
+        has_one = authority @ M0GovernanceError::Unauthorized
+    )]
+    pub governor: Account<'info, Governor>,
+}
+
+pub fn handler(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    let g = &mut ctx.accounts.governor;
+    if new_authority == Pubkey::default() || new_authority == g.authority {
+        return err!(M0GovernanceError::InvalidParameter);
+    }
+    g.pending_authority = Some(new_authority);
+    emit!(AuthorityTransferProposed {
+        authority: g.authority,
+        pending_authority: new_authority,
+        proposed_at_slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
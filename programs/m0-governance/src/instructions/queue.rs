@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::error::M0GovernanceError;
+use crate::events::*;
+use crate::state::governor::{Proposal, ProposalState, PROPOSAL_SEED};
+use crate::state::timelock::{Timelock, TIMELOCK_SEED};
+
+// Permissionless: Succeeded -> Queued, starting the min_delay_slots countdown.
+// Guardians can cancel while the proposal sits in the timelock.
+
+#[derive(Accounts)]
+pub struct Queue<'info> {
+    #[account(
+        seeds = [TIMELOCK_SEED],
+        bump = timelock.bump
+    )]
+    pub timelock: Account<'info, Timelock>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+pub fn handler(ctx: Context<Queue>) -> Result<()> {
+    let now = Clock::get()?.slot;
+    let t = &ctx.accounts.timelock;
+    let p = &mut ctx.accounts.proposal;
+
+    if p.state == ProposalState::Defeated {
+        // Surface why: QuorumNotMet or NotApproved.
+        p.check_passed()?;
+    }
+    if p.state != ProposalState::Succeeded {
+        return err!(M0GovernanceError::InvalidProposalState);
+    }
+    if now > p.expires_at_slot(t) {
+        return err!(M0GovernanceError::ProposalExpired);
+    }
+
+    p.state = ProposalState::Queued;
+    p.queued_at_slot = now;
+    p.eta_slot = now.saturating_add(t.min_delay_slots);
+
+    emit!(ProposalQueued {
+        proposal: p.key(),
+        proposal_id: p.proposal_id,
+        eta_slot: p.eta_slot,
+        expires_at_slot: p.expires_at_slot(t),
+        queued_at_slot: now,
+    });
+    Ok(())
+}
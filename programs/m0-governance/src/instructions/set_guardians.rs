@@ -1,22 +1,43 @@
-
 use anchor_lang::prelude::*;
 use crate::error::M0GovernanceError;
-use crate::state::governor::{Governor, GOVERNOR_SEED};
+use crate::events::*;
+use crate::state::governor::{Governor, GOVERNOR_SEED, MAX_GUARDIANS};
 
 #[derive(Accounts)]
+#[instruction(guardians: Vec<Pubkey>)]
 pub struct SetGuardians<'info> {
+    #[account(mut)]
     pub authority: Signer<'info>,
 
     #[account(
         mut,
         seeds = [GOVERNOR_SEED],
         bump = governor.bump,
-        has_one = authority @ M0GovernanceError::Unauthorized
+        has_one = authority @ M0GovernanceError::Unauthorized,
+        realloc = Governor::len_with(guardians.len(), governor.action_thresholds.len()),
+        realloc::payer = authority,
+        realloc::zero = false
     )]
     pub governor: Account<'info, Governor>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<SetGuardians>, guardians: Vec<Pubkey>) -> Result<()> {
-    ctx.accounts.governor.guardians = guardians;
+    if guardians.len() > MAX_GUARDIANS {
+        return err!(M0GovernanceError::InvalidParameter);
+    }
+    for (i, k) in guardians.iter().enumerate() {
+        if *k == Pubkey::default() || guardians[..i].contains(k) {
+            return err!(M0GovernanceError::InvalidParameter);
+        }
+    }
+    let g = &mut ctx.accounts.governor;
+    g.guardians = guardians;
+    emit!(GuardiansSet {
+        governor: g.key(),
+        guardians: g.guardians.clone(),
+        set_at_slot: Clock::get()?.slot,
+    });
     Ok(())
 }
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::error::M0GovernanceError;
+use crate::events::*;
+use crate::state::governor::{ActionThreshold, Governor, GOVERNOR_SEED, MAX_ACTION_THRESHOLDS};
+
+// Applies to proposals created afterwards; existing proposals keep their thresholds.
+
+#[derive(Accounts)]
+#[instruction(quorum_bps: u16, approval_bps: u16, action_thresholds: Vec<ActionThreshold>)]
+pub struct SetThresholds<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNOR_SEED],
+        bump = governor.bump,
+        has_one = authority @ M0GovernanceError::Unauthorized,
+        realloc = Governor::len_with(governor.guardians.len(), action_thresholds.len()),
+        realloc::payer = authority,
+        realloc::zero = false
+    )]
+    pub governor: Account<'info, Governor>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetThresholds>, quorum_bps: u16, approval_bps: u16, action_thresholds: Vec<ActionThreshold>) -> Result<()> {
+    if quorum_bps > 10_000 || approval_bps > 10_000 || action_thresholds.len() > MAX_ACTION_THRESHOLDS {
+        return err!(M0GovernanceError::InvalidParameter);
+    }
+    for t in &action_thresholds {
+        if t.quorum_bps > 10_000 || t.approval_bps > 10_000 {
+            return err!(M0GovernanceError::InvalidParameter);
+        }
+    }
+
+    let g = &mut ctx.accounts.governor;
+    g.quorum_bps = quorum_bps;
+    g.approval_bps = approval_bps;
+    g.action_thresholds = action_thresholds;
+
+    emit!(ThresholdsSet {
+        governor: g.key(),
+        quorum_bps,
+        approval_bps,
+        action_thresholds: g.action_thresholds.clone(),
+        set_at_slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
@@ -1,10 +1,16 @@
-
 use anchor_lang::prelude::*;
 use crate::error::M0GovernanceError;
-use crate::state::governor::{Proposal, GOVERNOR_SEED, PROPOSAL_SEED};
+use crate::events::*;
+use crate::state::governor::{Proposal, ProposalState, PROPOSAL_SEED};
+use crate::state::stake::{StakeEscrow, VoteRecord, STAKE_SEED, VOTE_SEED};
+
+// Weight is the voter's stake escrowed before the proposal was created, so stake
+// deposited after seeing a proposal can't decide it. Voting again before the period
+// ends replaces the earlier vote (and refreshes its weight).
 
 #[derive(Accounts)]
 pub struct Vote<'info> {
+    #[account(mut)]
     pub voter: Signer<'info>,
 
     #[account(
@@ -13,17 +19,55 @@ pub struct Vote<'info> {
         bump = proposal.bump
     )]
     pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_SEED, voter.key().as_ref()],
+        bump = escrow.bump,
+        constraint = escrow.owner == voter.key() @ M0GovernanceError::Unauthorized
+    )]
+    pub escrow: Account<'info, StakeEscrow>,
+
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = VoteRecord::LEN,
+        seeds = [VOTE_SEED, proposal.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<Vote>, support: bool, weight: u64) -> Result<()> {
+pub fn handler(ctx: Context<Vote>, support: bool) -> Result<()> {
     let now = Clock::get()?.slot;
     let p = &mut ctx.accounts.proposal;
 
+    if p.state != ProposalState::Active {
+        return err!(M0GovernanceError::InvalidProposalState);
+    }
     if now > p.voting_ends_at_slot {
         return err!(M0GovernanceError::VotingClosed);
     }
-    if p.executed {
-        return err!(M0GovernanceError::ProposalExecuted);
+
+    let weight = ctx.accounts.escrow.weight_at(p.created_at_slot);
+    if weight == 0 {
+        return err!(M0GovernanceError::NoVotingPower);
+    }
+
+    let r = &mut ctx.accounts.vote_record;
+    let changed = r.voter != Pubkey::default();
+    if changed {
+        if r.support {
+            p.yes_votes = p.yes_votes.saturating_sub(r.weight);
+        } else {
+            p.no_votes = p.no_votes.saturating_sub(r.weight);
+        }
+    } else {
+        r.proposal = p.key();
+        r.voter = ctx.accounts.voter.key();
+        r.bump = *ctx.bumps.get("vote_record").unwrap();
     }
 
     if support {
@@ -31,5 +75,22 @@ pub fn handler(ctx: Context<Vote>, support: bool, weight: u64) -> Result<()> {
     } else {
         p.no_votes = p.no_votes.saturating_add(weight);
     }
+    r.support = support;
+    r.weight = weight;
+    r.voted_at_slot = now;
+
+    let e = &mut ctx.accounts.escrow;
+    e.locked_until_slot = e.locked_until_slot.max(p.voting_ends_at_slot);
+
+    emit!(VoteCast {
+        proposal: p.key(),
+        voter: r.voter,
+        support,
+        weight,
+        changed,
+        yes_votes: p.yes_votes,
+        no_votes: p.no_votes,
+        voted_at_slot: now,
+    });
     Ok(())
 }
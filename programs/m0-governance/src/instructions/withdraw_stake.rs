@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::error::M0GovernanceError;
+use crate::events::*;
+use crate::state::governor::{Governor, GOVERNOR_SEED};
+use crate::state::stake::{StakeEscrow, STAKE_SEED};
+
+#[derive(Accounts)]
+pub struct WithdrawStake<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNOR_SEED],
+        bump = governor.bump
+    )]
+    pub governor: Account<'info, Governor>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_SEED, owner.key().as_ref()],
+        bump = escrow.bump,
+        has_one = owner @ M0GovernanceError::Unauthorized
+    )]
+    pub escrow: Account<'info, StakeEscrow>,
+
+    #[account(
+        mut,
+        constraint = owner_token.mint == governor.governance_mint @ M0GovernanceError::InvalidParameter
+    )]
+    pub owner_token: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = governor.stake_vault @ M0GovernanceError::InvalidParameter
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<WithdrawStake>, amount: u64) -> Result<()> {
+    let e = &mut ctx.accounts.escrow;
+    if amount == 0 || amount > e.amount {
+        return err!(M0GovernanceError::InvalidParameter);
+    }
+    if Clock::get()?.slot <= e.locked_until_slot {
+        return err!(M0GovernanceError::StakeLocked);
+    }
+
+    let bump = ctx.accounts.governor.bump;
+    let signer_seeds: &[&[u8]] = &[GOVERNOR_SEED, &[bump]];
+    let accounts = Transfer {
+        from: ctx.accounts.stake_vault.to_account_info(),
+        to: ctx.accounts.owner_token.to_account_info(),
+        authority: ctx.accounts.governor.to_account_info(),
+    };
+    token::transfer(CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), accounts, &[signer_seeds]), amount)?;
+
+    e.amount -= amount;
+    let g = &mut ctx.accounts.governor;
+    g.total_staked = g.total_staked.saturating_sub(amount);
+
+    emit!(StakeWithdrawn {
+        owner: e.owner,
+        amount,
+        staked: e.amount,
+        total_staked: g.total_staked,
+    });
+    Ok(())
+}
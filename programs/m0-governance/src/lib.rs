@@ -2,6 +2,7 @@
 use anchor_lang::prelude::*;
 
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
 
@@ -13,23 +14,63 @@ declare_id!("M0G0v3rnance111111111111111111111111111");
 pub mod m0_governance {
     use super::*;
 
-    pub fn init_governor(ctx: Context<init_governor::InitGovernor>, voting_period_slots: u64, quorum_bps: u16, min_delay_slots: u64) -> Result<()> {
-        init_governor::handler(ctx, voting_period_slots, quorum_bps, min_delay_slots)
+    pub fn init_governor(ctx: Context<init_governor::InitGovernor>, voting_period_slots: u64, quorum_bps: u16, approval_bps: u16, min_delay_slots: u64, grace_period_slots: u64) -> Result<()> {
+        init_governor::handler(ctx, voting_period_slots, quorum_bps, approval_bps, min_delay_slots, grace_period_slots)
     }
 
     pub fn set_guardians(ctx: Context<set_guardians::SetGuardians>, guardians: Vec<Pubkey>) -> Result<()> {
         set_guardians::handler(ctx, guardians)
     }
 
+    pub fn set_thresholds(ctx: Context<set_thresholds::SetThresholds>, quorum_bps: u16, approval_bps: u16, action_thresholds: Vec<state::governor::ActionThreshold>) -> Result<()> {
+        set_thresholds::handler(ctx, quorum_bps, approval_bps, action_thresholds)
+    }
+
     pub fn propose(ctx: Context<propose::Propose>, actions: Vec<state::governor::Action>) -> Result<()> {
         propose::handler(ctx, actions)
     }
 
-    pub fn vote(ctx: Context<vote::Vote>, support: bool, weight: u64) -> Result<()> {
-        vote::handler(ctx, support, weight)
+    pub fn deposit_stake(ctx: Context<deposit_stake::DepositStake>, amount: u64) -> Result<()> {
+        deposit_stake::handler(ctx, amount)
+    }
+
+    pub fn withdraw_stake(ctx: Context<withdraw_stake::WithdrawStake>, amount: u64) -> Result<()> {
+        withdraw_stake::handler(ctx, amount)
+    }
+
+    pub fn vote(ctx: Context<vote::Vote>, support: bool) -> Result<()> {
+        vote::handler(ctx, support)
+    }
+
+    pub fn finalize_vote(ctx: Context<finalize_vote::FinalizeVote>) -> Result<()> {
+        finalize_vote::handler(ctx)
+    }
+
+    pub fn queue(ctx: Context<queue::Queue>) -> Result<()> {
+        queue::handler(ctx)
     }
 
-    pub fn execute(ctx: Context<execute::Execute>) -> Result<()> {
+    pub fn cancel(ctx: Context<cancel::Cancel>) -> Result<()> {
+        cancel::handler(ctx)
+    }
+
+    pub fn expire(ctx: Context<expire::Expire>) -> Result<()> {
+        expire::handler(ctx)
+    }
+
+    pub fn execute<'info>(ctx: Context<'_, '_, 'info, 'info, execute::Execute<'info>>) -> Result<()> {
         execute::handler(ctx)
     }
+
+    pub fn propose_authority(ctx: Context<propose_authority::ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        propose_authority::handler(ctx, new_authority)
+    }
+
+    pub fn accept_authority(ctx: Context<accept_authority::AcceptAuthority>) -> Result<()> {
+        accept_authority::handler(ctx)
+    }
+
+    pub fn cancel_authority_transfer(ctx: Context<cancel_authority_transfer::CancelAuthorityTransfer>) -> Result<()> {
+        cancel_authority_transfer::handler(ctx)
+    }
 }
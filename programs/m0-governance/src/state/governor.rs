@@ -1,32 +1,100 @@
 
 use anchor_lang::prelude::*;
+use crate::error::M0GovernanceError;
 
 pub const GOVERNOR_SEED: &[u8] = b"governor";
 pub const PROPOSAL_SEED: &[u8] = b"proposal";
+pub const MAX_GUARDIANS: usize = 8;
+pub const MAX_ACTION_THRESHOLDS: usize = 16;
 
 #[account]
 pub struct Governor {
     pub authority: Pubkey,
+    pub pending_authority: Option<Pubkey>,
     pub guardians: Vec<Pubkey>,
+    pub governance_mint: Pubkey,
+    pub stake_vault: Pubkey, // token account owned by the governor PDA
+    pub total_staked: u64,
     pub voting_period_slots: u64,
-    pub quorum_bps: u16,
+    pub quorum_bps: u16,   // participation: (yes + no) / voting supply
+    pub approval_bps: u16, // yes / (yes + no)
+    pub action_thresholds: Vec<ActionThreshold>,
     pub proposal_count: u64,
     pub bump: u8,
 }
 
 impl Governor {
-    pub fn len_with(guardians_len: usize) -> usize {
-        8 + 32 + 4 + 32*guardians_len + 8 + 2 + 8 + 1
+    pub fn len_with(guardians_len: usize, thresholds_len: usize) -> usize {
+        8 + 32 + (1 + 32) + 4 + 32*guardians_len + 32 + 32 + 8 + 8 + 2 + 2 + 4 + thresholds_len * ActionThreshold::LEN + 8 + 1
+    }
+
+    /// Strictest (quorum_bps, approval_bps) over the defaults and every override
+    /// matching one of the actions.
+    pub fn thresholds_for(&self, actions: &[Action]) -> (u16, u16) {
+        let mut quorum = self.quorum_bps;
+        let mut approval = self.approval_bps;
+        for t in &self.action_thresholds {
+            if actions.iter().any(|a| t.matches(a)) {
+                quorum = quorum.max(t.quorum_bps);
+                approval = approval.max(t.approval_bps);
+            }
+        }
+        (quorum, approval)
     }
 }
 
+// Override for actions calling `program_id`, optionally only the instruction with this
+// Anchor discriminator (first 8 bytes of the data), e.g. stricter for signer-set rotation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ActionThreshold {
+    pub program_id: Pubkey,
+    pub discriminator: Option<[u8; 8]>, // None = every instruction of the program
+    pub quorum_bps: u16,
+    pub approval_bps: u16,
+}
+
+impl ActionThreshold {
+    pub const LEN: usize = 32 + (1 + 8) + 2 + 2;
+
+    pub fn matches(&self, a: &Action) -> bool {
+        if a.program_id != self.program_id {
+            return false;
+        }
+        match self.discriminator {
+            None => true,
+            Some(d) => a.data.len() >= 8 && a.data[..8] == d,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ActionAccount {
+    pub pubkey: Pubkey,
+    pub is_signer: bool, // only the governor PDA may sign (via invoke_signed)
+    pub is_writable: bool,
+}
+
+// Invoked by execute with the governor PDA as signer.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct Action {
     pub program_id: Pubkey,
-    pub accounts: Vec<Pubkey>,
+    pub accounts: Vec<ActionAccount>,
     pub data: Vec<u8>,
 }
 
+// Active -> Succeeded | Defeated (finalize_vote) -> Queued (queue) -> Executed (execute).
+// Cancelled (guardian, or proposer while Active) and Expired (grace period missed) are terminal.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProposalState {
+    Active,
+    Defeated,
+    Succeeded,
+    Queued,
+    Executed,
+    Cancelled,
+    Expired,
+}
+
 #[account]
 pub struct Proposal {
     pub governor: Pubkey,
@@ -36,7 +104,12 @@ pub struct Proposal {
     pub voting_ends_at_slot: u64,
     pub yes_votes: u64,
     pub no_votes: u64,
-    pub executed: bool,
+    pub voting_supply: u64, // Governor.total_staked when proposed
+    pub quorum_bps: u16,    // effective thresholds, fixed when proposed
+    pub approval_bps: u16,
+    pub state: ProposalState,
+    pub queued_at_slot: u64,
+    pub eta_slot: u64, // executable from this slot until eta_slot + grace_period_slots
     pub actions: Vec<Action>,
     pub bump: u8,
 }
@@ -44,6 +117,42 @@ pub struct Proposal {
 impl Proposal {
     pub fn len_with(actions_len: usize, max_data: usize, max_accounts: usize) -> usize {
         // Very conservative sizing; tune per your needs.
-        8 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 1 + 4 + actions_len * (32 + 4 + max_accounts*32 + 4 + max_data) + 1
+        8 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 2 + 2 + 1 + 8 + 8 + 4 + actions_len * (32 + 4 + max_accounts*(32 + 1 + 1) + 4 + max_data) + 1
+    }
+}
+
+impl Proposal {
+    /// Participation against the supply snapshotted by propose. Votes only carry stake
+    /// escrowed before the proposal was created, so they can't exceed it.
+    pub fn quorum_met(&self) -> bool {
+        let cast = self.yes_votes.saturating_add(self.no_votes) as u128;
+        cast > 0 && cast * 10_000 >= self.voting_supply as u128 * self.quorum_bps as u128
+    }
+
+    pub fn approved(&self) -> bool {
+        let cast = self.yes_votes.saturating_add(self.no_votes) as u128;
+        (self.yes_votes as u128) * 10_000 >= cast * self.approval_bps as u128
+    }
+
+    pub fn check_passed(&self) -> Result<()> {
+        if !self.quorum_met() {
+            return err!(M0GovernanceError::QuorumNotMet);
+        }
+        if !self.approved() {
+            return err!(M0GovernanceError::NotApproved);
+        }
+        Ok(())
+    }
+
+    pub fn is_final(&self) -> bool {
+        matches!(self.state, ProposalState::Defeated | ProposalState::Executed | ProposalState::Cancelled | ProposalState::Expired)
+    }
+
+    // Last slot at which a Succeeded proposal can be queued or a Queued one executed.
+    pub fn expires_at_slot(&self, t: &crate::state::timelock::Timelock) -> u64 {
+        match self.state {
+            ProposalState::Queued => self.eta_slot.saturating_add(t.grace_period_slots),
+            _ => self.voting_ends_at_slot.saturating_add(t.grace_period_slots),
+        }
     }
 }
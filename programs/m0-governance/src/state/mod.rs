@@ -1,3 +1,3 @@
-
 pub mod governor;
+pub mod stake;
 pub mod timelock;
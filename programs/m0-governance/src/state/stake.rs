@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+pub const STAKE_SEED: &[u8] = b"stake";
+pub const VOTE_SEED: &[u8] = b"vote";
+
+// Governance tokens locked in Governor.stake_vault, [STAKE_SEED, owner].
+// Voting power on a proposal is the stake held since before it was created (weight_at);
+// tokens can't leave while a vote they back is open.
+#[account]
+pub struct StakeEscrow {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub locked_until_slot: u64, // latest voting_ends_at_slot among proposals voted on
+    pub deposited_at_slot: u64,       // slot of the latest deposit
+    pub prior_amount: u64,            // amount before the latest deposit slot
+    pub prior_deposited_at_slot: u64, // latest deposit slot included in prior_amount
+    pub bump: u8,
+}
+
+impl StakeEscrow {
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 8 + 8 + 1;
+
+    /// Adds a deposit, keeping one checkpoint of the stake held before this slot.
+    pub fn record_deposit(&mut self, amount: u64, slot: u64) -> Option<()> {
+        if slot != self.deposited_at_slot {
+            self.prior_amount = self.amount;
+            self.prior_deposited_at_slot = self.deposited_at_slot;
+            self.deposited_at_slot = slot;
+        }
+        self.amount = self.amount.checked_add(amount)?;
+        Some(())
+    }
+
+    /// Stake escrowed before `slot` and still held. With two deposit slots at or after
+    /// `slot` the checkpoint is too recent, and the weight is 0.
+    pub fn weight_at(&self, slot: u64) -> u64 {
+        if self.deposited_at_slot < slot {
+            self.amount
+        } else if self.prior_deposited_at_slot < slot {
+            self.prior_amount.min(self.amount)
+        } else {
+            0
+        }
+    }
+}
+
+// One per (proposal, voter), [VOTE_SEED, proposal, voter]. Re-voting replaces the
+// previous choice and weight until voting closes.
+#[account]
+pub struct VoteRecord {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub support: bool,
+    pub weight: u64,
+    pub voted_at_slot: u64,
+    pub bump: u8,
+}
+
+impl VoteRecord {
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 8 + 8 + 1;
+}
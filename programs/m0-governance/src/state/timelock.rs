@@ -7,9 +7,10 @@ pub const TIMELOCK_SEED: &[u8] = b"timelock";
 pub struct Timelock {
     pub governor: Pubkey,
     pub min_delay_slots: u64,
+    pub grace_period_slots: u64, // after which an unexecuted proposal expires
     pub bump: u8,
 }
 
 impl Timelock {
-    pub const LEN: usize = 8 + 32 + 8 + 1;
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 1;
 }
@@ -0,0 +1,41 @@
+
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum M0ConsumerError {
+    #[msg("Prediction account does not belong to the expected market")]
+    MarketMismatch,
+
+    #[msg("Prediction account is not the canonical PDA")]
+    InvalidPredictionAccount,
+
+    #[msg("Prediction has never been published")]
+    NotPublished,
+
+    #[msg("Prediction is stale")]
+    StalePrediction,
+
+    #[msg("Risk score above consumer limit")]
+    RiskTooHigh,
+
+    #[msg("Quality flags rejected by consumer policy")]
+    QualityFlagsRejected,
+
+    #[msg("Prediction aggregated from too few publishers")]
+    TooFewPublishers,
+
+    #[msg("Prediction is under dispute")]
+    Disputed,
+
+    #[msg("Prediction has been retracted")]
+    Retracted,
+
+    #[msg("Prediction has been superseded by a corrective bundle")]
+    Superseded,
+
+    #[msg("Resolution account is not the canonical PDA")]
+    InvalidResolutionAccount,
+
+    #[msg("Market has not been resolved")]
+    NotResolved,
+}
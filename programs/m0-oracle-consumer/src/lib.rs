@@ -0,0 +1,7 @@
+
+pub mod error;
+pub mod reader;
+
+pub use m0_oracle::state::prediction::{LatestPrediction, PredictedOutcome};
+pub use m0_oracle::state::resolution::{MarketResolution, ResolutionOutcome};
+pub use reader::{read_latest_prediction, read_resolution, ReadPolicy};
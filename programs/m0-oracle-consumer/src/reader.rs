@@ -0,0 +1,99 @@
+
+use anchor_lang::prelude::*;
+use m0_oracle::constants::{PREDICTION_SEED, RESOLUTION_SEED};
+use m0_oracle::state::epoch::OutputStatus;
+use m0_oracle::state::prediction::LatestPrediction;
+use m0_oracle::state::resolution::MarketResolution;
+use crate::error::M0ConsumerError;
+
+// Read path for consumer programs.
+// Pass the oracle's LatestPrediction PDA as an account of your instruction and
+// call `read_latest_prediction` with a policy that fits your risk tolerance.
+
+#[derive(Clone, Copy, Debug)]
+pub struct ReadPolicy {
+    /// Reject predictions last updated more than this many slots ago.
+    pub max_staleness_slots: u64,
+    /// Reject predictions whose risk_score is above this value.
+    pub max_risk_score: u16,
+    /// Reject predictions with any of these quality flag bits set.
+    pub rejected_quality_flags: u32,
+    /// Reject predictions built from fewer publishers (aggregated markets).
+    pub min_publishers: u8,
+    /// Accept outputs with an open or accepted dispute. Retracted and superseded outputs are always rejected.
+    pub allow_disputed: bool,
+}
+
+impl Default for ReadPolicy {
+    fn default() -> Self {
+        Self {
+            max_staleness_slots: 150,
+            max_risk_score: u16::MAX,
+            rejected_quality_flags: 0,
+            min_publishers: 1,
+            allow_disputed: false,
+        }
+    }
+}
+
+/// Loads the LatestPrediction for `market` and enforces `policy` against the current slot.
+pub fn read_latest_prediction(prediction_info: &AccountInfo, market: &Pubkey, policy: &ReadPolicy) -> Result<LatestPrediction> {
+    let (expected, _) = Pubkey::find_program_address(&[PREDICTION_SEED, market.as_ref()], &m0_oracle::ID);
+    if prediction_info.key() != expected || prediction_info.owner != &m0_oracle::ID {
+        return err!(M0ConsumerError::InvalidPredictionAccount);
+    }
+
+    // try_deserialize checks the account discriminator.
+    let data = prediction_info.try_borrow_data()?;
+    let p = LatestPrediction::try_deserialize(&mut &data[..])?;
+
+    if p.market != *market {
+        return err!(M0ConsumerError::MarketMismatch);
+    }
+    if p.sequence == 0 || p.outcomes.is_empty() {
+        return err!(M0ConsumerError::NotPublished);
+    }
+
+    match p.status {
+        OutputStatus::Valid => {}
+        OutputStatus::Disputed if policy.allow_disputed => {}
+        OutputStatus::Disputed => return err!(M0ConsumerError::Disputed),
+        OutputStatus::Retracted => return err!(M0ConsumerError::Retracted),
+        OutputStatus::Superseded => return err!(M0ConsumerError::Superseded),
+    }
+
+    let now = Clock::get()?.slot;
+    if now.saturating_sub(p.updated_at_slot) > policy.max_staleness_slots {
+        return err!(M0ConsumerError::StalePrediction);
+    }
+    if p.risk_score > policy.max_risk_score {
+        return err!(M0ConsumerError::RiskTooHigh);
+    }
+    if p.quality_flags & policy.rejected_quality_flags != 0 {
+        return err!(M0ConsumerError::QualityFlagsRejected);
+    }
+    if p.publisher_count < policy.min_publishers {
+        return err!(M0ConsumerError::TooFewPublishers);
+    }
+
+    Ok(p)
+}
+
+/// Loads the final MarketResolution for `market`. An address with no account yet means
+/// the market is still unresolved.
+pub fn read_resolution(resolution_info: &AccountInfo, market: &Pubkey) -> Result<MarketResolution> {
+    let (expected, _) = Pubkey::find_program_address(&[RESOLUTION_SEED, market.as_ref()], &m0_oracle::ID);
+    if resolution_info.key() != expected {
+        return err!(M0ConsumerError::InvalidResolutionAccount);
+    }
+    if resolution_info.owner != &m0_oracle::ID {
+        return err!(M0ConsumerError::NotResolved);
+    }
+
+    let data = resolution_info.try_borrow_data()?;
+    let r = MarketResolution::try_deserialize(&mut &data[..])?;
+    if r.market != *market {
+        return err!(M0ConsumerError::MarketMismatch);
+    }
+    Ok(r)
+}
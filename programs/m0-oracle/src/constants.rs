@@ -7,8 +7,52 @@ pub const COMMIT_SEED: &[u8] = b"commit";
 pub const AUDIT_SEED: &[u8] = b"audit";
 
 pub const PROB_SCALE: u64 = 1_000_000_000;
+pub const PROB_SUM_TOLERANCE_PER_OUTCOME: u64 = 1;
 pub const MAX_OUTCOMES: usize = 16;
 pub const MAX_MARKET_ID_LEN: usize = 64;
 pub const MAX_OUTCOME_ID_LEN: usize = 64;
+pub const MAX_TIER_POLICY_LEN: usize = 16; // m0-registry MAX_TIER_POLICY_LEN
 
 pub const DEFAULT_REVEAL_DELAY_SLOTS: u64 = 10;
+pub const DEFAULT_MAX_REVEAL_DELAY_SLOTS: u64 = 150;
+
+// ~24h at 400ms slots (upper end of the production stability window).
+pub const DEFAULT_MAX_SIGNER_OVERLAP_SLOTS: u64 = 216_000;
+
+pub const CHUNKED_REVEAL_SEED: &[u8] = b"chunked_reveal";
+pub const MAX_MERKLE_CHUNKS: u32 = 256;
+pub const MAX_MERKLE_PROOF_LEN: usize = 8;
+
+pub const PREDICTION_SEED: &[u8] = b"prediction";
+
+pub const ROLE_SEED: &[u8] = b"role";
+
+pub const HISTORY_SEED: &[u8] = b"history";
+pub const REVEAL_HISTORY_CAPACITY: usize = 64;
+
+pub const SUBMISSION_SEED: &[u8] = b"submission";
+pub const DEFAULT_PUBLISHER_WEIGHT: u16 = 1;
+pub const MAX_AGGREGATE_PUBLISHERS: usize = 16;
+
+pub const DISPUTE_SEED: &[u8] = b"dispute";
+
+pub const RESOLUTION_SEED: &[u8] = b"resolution";
+// ~24h at 400ms slots; lower end of docs/protocol-spec/dispute-resolution.md.
+pub const DEFAULT_DISPUTE_WINDOW_SLOTS: u64 = 216_000;
+// Every open dispute marks the output Disputed, so opening one must cost something;
+// set_dispute_params refuses 0. 0.1 SOL, forfeited to the FeeVault on rejection.
+pub const DEFAULT_MIN_DISPUTE_BOND_LAMPORTS: u64 = 100_000_000;
+
+// docs/protocol-spec/epoch-rounding.md: epoch_index = (t_ms - origin_ms) / epoch_window_ms.
+// Protocol constant; changing it renumbers every epoch.
+pub const EPOCH_ORIGIN_MS: u64 = 0;
+pub const FUTURE_SKEW_MS: u64 = 15_000;
+// Covers the default reveal window (10 + 150 slots at 400ms) after window_end_ms.
+pub const DEFAULT_FINALIZATION_DELAY_MS: u64 = 90_000;
+
+// commit_batch / reveal_batch. Bundle bytes travel inline, so the 1232-byte packet binds
+// first: each two-outcome market adds ~144 bytes even with its accounts in a lookup
+// table (tests/batch_size.rs). Larger engine bundles go through reveal_prediction.
+pub const MAX_BATCH_MARKETS: usize = 4;
+pub const BATCH_COMMIT_ACCOUNTS: usize = 3; // market, epoch, commit
+pub const BATCH_REVEAL_ACCOUNTS: usize = 6; // market, epoch, commit, audit, history, prediction
@@ -65,4 +65,109 @@ pub enum M0OracleError {
 
     #[msg("Invalid parameter")]
     InvalidParameter,
+
+    #[msg("Epoch mismatch")]
+    EpochMismatch,
+
+    #[msg("No pending authority transfer")]
+    NoPendingAuthority,
+
+    #[msg("Reveal deadline passed")]
+    RevealDeadlinePassed,
+
+    #[msg("Reveal deadline not reached")]
+    RevealDeadlineNotReached,
+
+    #[msg("Epoch not finalized")]
+    EpochNotFinalized,
+
+    #[msg("Commit not settled")]
+    CommitNotSettled,
+
+    #[msg("Epoch has open commits")]
+    EpochHasOpenCommits,
+
+    #[msg("Signer set mismatch")]
+    SignerSetMismatch,
+
+    #[msg("Signer set expired")]
+    SignerSetExpired,
+
+    #[msg("Signer set rotation already pending")]
+    SignerSetRotationPending,
+
+    #[msg("Signer set activation slot not reached")]
+    ActivationSlotNotReached,
+
+    #[msg("Signer set overlap window too long")]
+    SignerOverlapTooLong,
+
+    #[msg("Aggregation mode requires a full bundle reveal")]
+    AggregationRequiresFullReveal,
+
+    #[msg("Invalid publisher submission")]
+    InvalidSubmission,
+
+    #[msg("Dispute window closed")]
+    DisputeWindowClosed,
+
+    #[msg("Dispute window still open")]
+    DisputeWindowOpen,
+
+    #[msg("Dispute bond too low")]
+    DisputeBondTooLow,
+
+    #[msg("Dispute not open")]
+    DisputeNotOpen,
+
+    #[msg("Dispute not accepted")]
+    DisputeNotAccepted,
+
+    #[msg("Epoch has open disputes")]
+    EpochHasOpenDisputes,
+
+    #[msg("Epoch output already corrected")]
+    OutputAlreadyCorrected,
+
+    #[msg("Invalid epoch window or publish cadence")]
+    InvalidEpochWindow,
+
+    #[msg("Epoch id does not match the current time window")]
+    EpochWindowMismatch,
+
+    #[msg("Epoch window is stale")]
+    EpochWindowStale,
+
+    #[msg("Epoch window is in the future")]
+    EpochWindowInFuture,
+
+    #[msg("Epoch window not elapsed")]
+    EpochWindowNotElapsed,
+
+    #[msg("Invalid batch accounts")]
+    InvalidBatch,
+
+    #[msg("Too many markets in batch")]
+    BatchTooLarge,
+
+    #[msg("Market does not match registry metadata")]
+    RegistryMismatch,
+
+    #[msg("Market not active in registry")]
+    RegistryMarketInactive,
+
+    #[msg("Market must be inactive")]
+    MarketMustBeInactive,
+
+    #[msg("Market already resolved")]
+    MarketResolved,
+
+    #[msg("Dispute still open or awaiting correction")]
+    DisputeNotSettled,
+
+    #[msg("Fee router accounts required for a market with a publish fee")]
+    FeeAccountsRequired,
+
+    #[msg("Reveal path does not match the market's reveal mode")]
+    RevealModeMismatch,
 }
@@ -1,5 +1,8 @@
 
 use anchor_lang::prelude::*;
+use crate::state::dispute::DisputeReason;
+use crate::state::resolution::ResolutionOutcome;
+use crate::state::role::{Role, RoleScope};
 
 #[event]
 pub struct ProtocolInitialized {
@@ -11,6 +14,8 @@ pub struct ProtocolInitialized {
 pub struct MarketCreated {
     pub market: Pubkey,
     pub market_id: String,
+    pub epoch_window_ms: u64,
+    pub publish_cadence_ms: u64,
     pub created_at_slot: u64,
 }
 
@@ -19,14 +24,51 @@ pub struct MarketUpdated {
     pub market: Pubkey,
     pub market_id: String,
     pub active: bool,
+    pub min_publishers: u8,
+    pub publish_fee_lamports: u64,
+    pub merkle_reveals: bool,
     pub updated_at_slot: u64,
 }
 
+#[event]
+pub struct MarketSynced {
+    pub market: Pubkey,
+    pub market_id: String,
+    pub active: bool,
+    pub publish_cadence_ms: u64,
+    pub tier_policy: String,
+    pub outcomes_changed: bool,
+    pub registry_updated_at_slot: u64,
+    pub synced_at_slot: u64,
+}
+
+#[event]
+pub struct ResolutionSignerSetSet {
+    pub signer_set: Pubkey,
+    pub signer_set_id: u64,
+    pub threshold: u16,
+    pub previous_signer_set_id: u64,
+}
+
+#[event]
+pub struct MarketResolved {
+    pub market: Pubkey,
+    pub market_id: String,
+    pub resolution: Pubkey,
+    pub outcome: ResolutionOutcome,
+    pub winning_outcome_id: Option<String>,
+    pub resolution_source_hash: [u8; 32],
+    pub signer_set_id: u64,
+    pub resolution_slot: u64,
+}
+
 #[event]
 pub struct EpochOpened {
     pub epoch: Pubkey,
     pub market: Pubkey,
     pub epoch_id: u64,
+    pub window_start_ms: u64,
+    pub window_end_ms: u64,
     pub opened_at_slot: u64,
 }
 
@@ -37,6 +79,33 @@ pub struct PredictionCommitted {
     pub committer: Pubkey,
     pub commit_hash: [u8; 32],
     pub reveal_after_slot: u64,
+    pub reveal_deadline_slot: u64,
+}
+
+#[event]
+pub struct PublishFeePaid {
+    pub market: Pubkey,
+    pub payer: Pubkey,
+    pub fee_vault: Pubkey,
+    pub amount_lamports: u64,
+    pub paid_at_slot: u64,
+}
+
+#[event]
+pub struct BatchCommitted {
+    pub committer: Pubkey,
+    pub commit_hash: [u8; 32],
+    pub market_count: u8,
+    pub fee_lamports: u64,
+    pub committed_at_slot: u64,
+}
+
+#[event]
+pub struct BatchRevealed {
+    pub revealer: Pubkey,
+    pub bundle_hash: [u8; 32],
+    pub market_count: u8,
+    pub revealed_at_slot: u64,
 }
 
 #[event]
@@ -56,15 +125,224 @@ pub struct EpochFinalized {
     pub finalized_at_slot: u64,
 }
 
+#[event]
+pub struct EpochAggregated {
+    pub epoch: Pubkey,
+    pub market: Pubkey,
+    pub epoch_id: u64,
+    pub publisher_count: u8,
+    pub min_publishers: u8,
+    pub quorum_met: bool,
+    pub aggregate_hash: [u8; 32],
+}
+
 #[event]
 pub struct SignerSetRotated {
     pub signer_set: Pubkey,
     pub signer_set_id: u64,
     pub threshold: u16,
-    pub active: bool,
+    pub activation_slot: u64,
+    pub previous_signer_set_id: u64,
+    pub previous_expires_at_slot: u64,
+}
+
+#[event]
+pub struct SignerSetActivated {
+    pub signer_set: Pubkey,
+    pub signer_set_id: u64,
+    pub previous_signer_set_id: u64,
+    pub activated_at_slot: u64,
+}
+
+#[event]
+pub struct SignerSetDeactivated {
+    pub signer_set: Pubkey,
+    pub signer_set_id: u64,
+    pub was_current: bool,
+    pub deactivated_at_slot: u64,
 }
 
 #[event]
 pub struct PausedChanged {
     pub paused: bool,
 }
+
+#[event]
+pub struct MerkleRootRevealed {
+    pub market: Pubkey,
+    pub epoch: Pubkey,
+    pub chunked: Pubkey,
+    pub revealer: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub chunk_count: u32,
+    pub bundle_hash: [u8; 32], // hash_merkle_commitment(merkle_root, chunk_count)
+    pub sequence: u64,
+}
+
+#[event]
+pub struct ChunkRevealed {
+    pub chunked: Pubkey,
+    pub market: Pubkey,
+    pub epoch: Pubkey,
+    pub chunk_index: u32,
+    pub chunk_hash: [u8; 32],
+    pub revealed_at_slot: u64,
+}
+
+#[event]
+pub struct ChunkedRevealCompleted {
+    pub chunked: Pubkey,
+    pub market: Pubkey,
+    pub epoch: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub chunk_count: u32,
+    pub sequence: u64,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub assignment: Pubkey,
+    pub role: Role,
+    pub grantee: Pubkey,
+    pub scope: RoleScope,
+    pub granted_by: Pubkey,
+    pub granted_at_slot: u64,
+    pub weight: u16,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub assignment: Pubkey,
+    pub role: Role,
+    pub grantee: Pubkey,
+    pub scope: RoleScope,
+    pub revoked_by: Pubkey,
+    pub revoked_at_slot: u64,
+}
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub proposed_at_slot: u64,
+}
+
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub authority: Pubkey,
+    pub cancelled_pending_authority: Pubkey,
+    pub cancelled_at_slot: u64,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub accepted_at_slot: u64,
+}
+
+#[event]
+pub struct CommitExpired {
+    pub commit: Pubkey,
+    pub market: Pubkey,
+    pub epoch: Pubkey,
+    pub committer: Pubkey,
+    pub commit_hash: [u8; 32],
+    pub reveal_deadline_slot: u64,
+    pub expired_at_slot: u64,
+}
+
+#[event]
+pub struct CommitClosed {
+    pub commit: Pubkey,
+    pub epoch: Pubkey,
+    pub committer: Pubkey,
+    pub revealed: bool,
+    pub expired: bool,
+    pub closed_at_slot: u64,
+}
+
+#[event]
+pub struct EpochClosed {
+    pub epoch: Pubkey,
+    pub market: Pubkey,
+    pub epoch_id: u64,
+    pub commit_count: u32,
+    pub missed_reveals: u32,
+    pub closed_at_slot: u64,
+}
+
+#[event]
+pub struct RevealHistoryInitialized {
+    pub market: Pubkey,
+    pub history: Pubkey,
+    pub capacity: u32,
+}
+
+#[event]
+pub struct DisputeOpened {
+    pub dispute: Pubkey,
+    pub market: Pubkey,
+    pub epoch: Pubkey,
+    pub epoch_id: u64,
+    pub bundle_hash: [u8; 32],
+    pub reason: DisputeReason,
+    pub reporter: Pubkey,
+    pub evidence_hash: [u8; 32],
+    pub bond_lamports: u64,
+    pub opened_at_slot: u64,
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub dispute: Pubkey,
+    pub market: Pubkey,
+    pub epoch: Pubkey,
+    pub epoch_id: u64,
+    pub bundle_hash: [u8; 32],
+    pub accepted: bool,
+    pub resolver: Pubkey,
+    pub evidence_hash: [u8; 32],
+    pub bond_lamports: u64,
+    pub resolved_at_slot: u64,
+}
+
+#[event]
+pub struct DisputeClosed {
+    pub dispute: Pubkey,
+    pub epoch: Pubkey,
+    pub reporter: Pubkey,
+    pub accepted: bool,
+    pub closed_at_slot: u64,
+}
+
+#[event]
+pub struct OutputRetracted {
+    pub market: Pubkey,
+    pub epoch: Pubkey,
+    pub epoch_id: u64,
+    pub target_bundle_hash: [u8; 32],
+    pub dispute: Pubkey,
+    pub evidence_hash: [u8; 32],
+    pub resolver: Pubkey,
+    pub applied_at_slot: u64,
+}
+
+#[event]
+pub struct OutputSuperseded {
+    pub market: Pubkey,
+    pub epoch: Pubkey,
+    pub epoch_id: u64,
+    pub target_bundle_hash: [u8; 32],
+    pub corrective_bundle_hash: [u8; 32],
+    pub dispute: Pubkey,
+    pub evidence_hash: [u8; 32],
+    pub resolver: Pubkey,
+    pub applied_at_slot: u64,
+}
+
+#[event]
+pub struct DisputeParamsSet {
+    pub dispute_window_slots: u64,
+    pub min_dispute_bond_lamports: u64,
+}
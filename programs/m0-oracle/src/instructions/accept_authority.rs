@@ -0,0 +1,41 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::M0OracleError;
+use crate::events::*;
+use crate::state::config::ProtocolConfig;
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROTOCOL_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+}
+
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let cfg = &mut ctx.accounts.config;
+    let signer = ctx.accounts.new_authority.key();
+
+    match cfg.pending_authority {
+        None => return err!(M0OracleError::NoPendingAuthority),
+        Some(p) if p != signer => return err!(M0OracleError::Unauthorized),
+        Some(_) => {}
+    }
+
+    let previous = cfg.authority;
+    cfg.authority = signer;
+    cfg.pending_authority = None;
+
+    emit!(AuthorityTransferred {
+        previous_authority: previous,
+        new_authority: signer,
+        accepted_at_slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
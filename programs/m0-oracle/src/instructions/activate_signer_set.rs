@@ -0,0 +1,58 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::M0OracleError;
+use crate::events::*;
+use crate::state::config::ProtocolConfig;
+use crate::state::signer_set::SignerSet;
+
+// Permissionless crank: records the scheduled set as current once its activation slot
+// is reached. Reveal validity does not depend on this; it follows each set's own window.
+
+#[derive(Accounts)]
+pub struct ActivateSignerSet<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROTOCOL_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        seeds = [SIGNER_SET_SEED, &signer_set.signer_set_id.to_le_bytes()],
+        bump = signer_set.bump
+    )]
+    pub signer_set: Account<'info, SignerSet>,
+}
+
+pub fn handler(ctx: Context<ActivateSignerSet>) -> Result<()> {
+    let cfg = &mut ctx.accounts.config;
+    let ss = &ctx.accounts.signer_set;
+
+    if cfg.pending_signer_set_id == 0 || ss.signer_set_id != cfg.pending_signer_set_id {
+        return err!(M0OracleError::SignerSetMismatch);
+    }
+    if !ss.active {
+        return err!(M0OracleError::SignerSetNotActive);
+    }
+
+    let now = Clock::get()?.slot;
+    if now < ss.activation_slot {
+        return err!(M0OracleError::ActivationSlotNotReached);
+    }
+
+    let prev_id = cfg.active_signer_set_id;
+    cfg.active_signer_set_id = ss.signer_set_id;
+    cfg.pending_signer_set_id = 0;
+
+    emit!(SignerSetActivated {
+        signer_set: ss.key(),
+        signer_set_id: ss.signer_set_id,
+        previous_signer_set_id: prev_id,
+        activated_at_slot: now,
+    });
+
+    Ok(())
+}
@@ -1,12 +1,12 @@
 
 use anchor_lang::prelude::*;
+use crate::constants::*;
 use crate::error::M0OracleError;
+use crate::events::*;
 use crate::state::config::ProtocolConfig;
-use crate::constants::*;
 
 #[derive(Accounts)]
-pub struct UpgradeAdmin<'info> {
-    #[account(mut)]
+pub struct CancelAuthorityTransfer<'info> {
     pub authority: Signer<'info>,
 
     #[account(
@@ -18,8 +18,15 @@ pub struct UpgradeAdmin<'info> {
     pub config: Account<'info, ProtocolConfig>,
 }
 
-pub fn handler(ctx: Context<UpgradeAdmin>, new_authority: Pubkey) -> Result<()> {
+pub fn handler(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
     let cfg = &mut ctx.accounts.config;
-    cfg.authority = new_authority;
+    let pending = cfg.pending_authority.take().ok_or(M0OracleError::NoPendingAuthority)?;
+
+    emit!(AuthorityTransferCancelled {
+        authority: cfg.authority,
+        cancelled_pending_authority: pending,
+        cancelled_at_slot: Clock::get()?.slot,
+    });
+
     Ok(())
 }
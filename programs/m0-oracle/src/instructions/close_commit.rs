@@ -0,0 +1,97 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::M0OracleError;
+use crate::events::*;
+use crate::state::chunked::ChunkedReveal;
+use crate::state::commit::CommitRecord;
+use crate::state::config::ProtocolConfig;
+use crate::state::epoch::Epoch;
+use crate::state::submission::PublisherSubmission;
+
+// Returns commit rent to the committer once the epoch is finalized, the dispute window
+// has passed (an expired commit is m0-slashing evidence until then) and the commit was
+// either revealed or expired. The ChunkedReveal / PublisherSubmission the reveal created
+// must be closed along with it. Permissionless since rent only goes back to the payer.
+
+#[derive(Accounts)]
+pub struct CloseCommit<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        address = commit.committer @ M0OracleError::Unauthorized
+    )]
+    pub committer: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [EPOCH_SEED, epoch.market.as_ref(), &epoch.epoch_id.to_le_bytes()],
+        bump = epoch.bump
+    )]
+    pub epoch: Account<'info, Epoch>,
+
+    #[account(
+        mut,
+        close = committer,
+        seeds = [COMMIT_SEED, epoch.key().as_ref(), commit.committer.as_ref()],
+        bump = commit.bump
+    )]
+    pub commit: Account<'info, CommitRecord>,
+
+    // Required when the commit was revealed in merkle chunk mode (commit.has_chunked).
+    #[account(
+        mut,
+        close = committer,
+        seeds = [CHUNKED_REVEAL_SEED, commit.key().as_ref()],
+        bump = chunked.bump
+    )]
+    pub chunked: Option<Account<'info, ChunkedReveal>>,
+
+    // Required when the reveal wrote a submission (commit.has_submission).
+    #[account(
+        mut,
+        close = committer,
+        seeds = [SUBMISSION_SEED, epoch.key().as_ref(), commit.committer.as_ref()],
+        bump = submission.bump
+    )]
+    pub submission: Option<Account<'info, PublisherSubmission>>,
+}
+
+pub fn handler(ctx: Context<CloseCommit>) -> Result<()> {
+    let e = &mut ctx.accounts.epoch;
+    if e.open || e.finalized_at_slot == 0 {
+        return err!(M0OracleError::EpochNotFinalized);
+    }
+
+    if Clock::get()?.slot <= e.finalized_at_slot.saturating_add(ctx.accounts.config.dispute_window_slots) {
+        return err!(M0OracleError::DisputeWindowOpen);
+    }
+
+    let c = &ctx.accounts.commit;
+    if !c.is_settled() {
+        return err!(M0OracleError::CommitNotSettled);
+    }
+    if c.has_chunked != ctx.accounts.chunked.is_some() || c.has_submission != ctx.accounts.submission.is_some() {
+        return err!(M0OracleError::InvalidParameter);
+    }
+
+    e.open_commits = e.open_commits.saturating_sub(1);
+
+    emit!(CommitClosed {
+        commit: c.key(),
+        epoch: c.epoch,
+        committer: c.committer,
+        revealed: c.revealed,
+        expired: c.expired,
+        closed_at_slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
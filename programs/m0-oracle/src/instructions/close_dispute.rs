@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::M0OracleError;
+use crate::events::*;
+use crate::state::dispute::{CorrectionAction, DisputeRecord, DisputeState};
+
+// Returns dispute rent to the reporter once the dispute is settled: rejected, or
+// accepted and its correction applied by supersede_epoch. The bond has already been
+// released by resolve_dispute. Permissionless since rent only goes back to the payer.
+
+#[derive(Accounts)]
+pub struct CloseDispute<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        address = dispute.reporter @ M0OracleError::Unauthorized
+    )]
+    pub reporter: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        close = reporter,
+        seeds = [DISPUTE_SEED, dispute.epoch.as_ref(), dispute.bundle_hash.as_ref()],
+        bump = dispute.bump
+    )]
+    pub dispute: Account<'info, DisputeRecord>,
+}
+
+pub fn handler(ctx: Context<CloseDispute>) -> Result<()> {
+    let d = &ctx.accounts.dispute;
+    let settled = match d.state {
+        DisputeState::Open => false,
+        DisputeState::Accepted => d.action != CorrectionAction::None,
+        DisputeState::Rejected => true,
+    };
+    if !settled {
+        return err!(M0OracleError::DisputeNotSettled);
+    }
+
+    emit!(DisputeClosed {
+        dispute: d.key(),
+        epoch: d.epoch,
+        reporter: d.reporter,
+        accepted: d.state == DisputeState::Accepted,
+        closed_at_slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::M0OracleError;
+use crate::events::*;
+use crate::state::audit::AuditLog;
+use crate::state::config::ProtocolConfig;
+use crate::state::epoch::Epoch;
+
+// Returns epoch (and audit log) rent once the epoch is finalized, the dispute window
+// has passed and every commit has been closed. Permissionless since rent only goes
+// back to the original payers.
+
+#[derive(Accounts)]
+pub struct CloseEpoch<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        address = epoch.rent_payer @ M0OracleError::Unauthorized
+    )]
+    pub rent_payer: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [EPOCH_SEED, epoch.market.as_ref(), &epoch.epoch_id.to_le_bytes()],
+        bump = epoch.bump
+    )]
+    pub epoch: Account<'info, Epoch>,
+
+    // Required when the epoch had at least one reveal.
+    #[account(
+        mut,
+        seeds = [AUDIT_SEED, epoch.key().as_ref()],
+        bump = audit.bump
+    )]
+    pub audit: Option<Account<'info, AuditLog>>,
+
+    /// CHECK: must equal audit.rent_payer; only receives lamports.
+    #[account(mut)]
+    pub audit_rent_payer: Option<UncheckedAccount<'info>>,
+}
+
+pub fn handler(ctx: Context<CloseEpoch>) -> Result<()> {
+    let e = &ctx.accounts.epoch;
+    if e.open || e.finalized_at_slot == 0 {
+        return err!(M0OracleError::EpochNotFinalized);
+    }
+    if e.open_commits > 0 {
+        return err!(M0OracleError::EpochHasOpenCommits);
+    }
+    if e.open_disputes > 0 {
+        return err!(M0OracleError::EpochHasOpenDisputes);
+    }
+    if Clock::get()?.slot <= e.finalized_at_slot.saturating_add(ctx.accounts.config.dispute_window_slots) {
+        return err!(M0OracleError::DisputeWindowOpen);
+    }
+
+    match (&ctx.accounts.audit, &ctx.accounts.audit_rent_payer) {
+        (Some(audit), Some(dest)) => {
+            if dest.key() != audit.rent_payer {
+                return err!(M0OracleError::Unauthorized);
+            }
+            audit.close(dest.to_account_info())?;
+        }
+        (None, _) if e.publish_sequence == 0 => {}
+        _ => return err!(M0OracleError::InvalidParameter),
+    }
+
+    emit!(EpochClosed {
+        epoch: e.key(),
+        market: e.market,
+        epoch_id: e.epoch_id,
+        commit_count: e.commit_count,
+        missed_reveals: e.missed_reveals,
+        closed_at_slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
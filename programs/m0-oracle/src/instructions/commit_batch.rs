@@ -0,0 +1,171 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::events::*;
+use crate::error::M0OracleError;
+use crate::state::commit::CommitRecord;
+use crate::state::config::ProtocolConfig;
+use crate::state::epoch::Epoch;
+use crate::state::market::Market;
+use crate::state::role::{Role, RoleAssignment};
+use crate::utils::access::require_role;
+use crate::utils::accounts::{create_pda, load, require_pda, store};
+use crate::utils::fees::{charge_publish_fee, FeeAccounts};
+use crate::utils::time::unix_ms;
+use m0_fee_router::program::M0FeeRouter;
+use m0_fee_router::state::ledger::{FeeLedger, LEDGER_SEED, SOL_LEDGER_MINT};
+use m0_fee_router::state::router::{Router, ROUTER_SEED};
+use m0_fee_router::state::vaults::{FeeVault, VAULT_SEED};
+
+// One bundle commit for up to MAX_BATCH_MARKETS markets. remaining_accounts holds
+// BATCH_COMMIT_ACCOUNTS per market: [market, epoch (mut), commit (mut, uninitialized)].
+// A regular CommitRecord is written for every market, so expiry, rent reclamation and
+// slashing work the same as for commit_prediction. The role must cover every market, and
+// each market's publish fee is charged.
+
+#[derive(Accounts)]
+pub struct CommitBatch<'info> {
+    #[account(mut)]
+    pub committer: Signer<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    // Publisher RoleAssignment of the signer; omit when the signer is the protocol authority.
+    pub role: Option<Account<'info, RoleAssignment>>,
+
+    // m0-fee-router accounts for the publish fee, paid into the native SOL ledger.
+    // Only required when a publish fee is charged.
+    #[account(
+        seeds = [ROUTER_SEED],
+        bump = fee_router.bump,
+        seeds::program = m0_fee_router::ID
+    )]
+    pub fee_router: Option<Account<'info, Router>>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED],
+        bump = fee_vault.bump,
+        seeds::program = m0_fee_router::ID
+    )]
+    pub fee_vault: Option<Account<'info, FeeVault>>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, SOL_LEDGER_MINT.as_ref()],
+        bump = fee_ledger.bump,
+        seeds::program = m0_fee_router::ID
+    )]
+    pub fee_ledger: Option<Account<'info, FeeLedger>>,
+
+    pub fee_router_program: Option<Program<'info, M0FeeRouter>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, CommitBatch<'info>>, commit_hash: [u8; 32], reveal_delay_slots: Option<u64>) -> Result<()> {
+    let cfg = &ctx.accounts.config;
+    if cfg.paused {
+        return err!(M0OracleError::Paused);
+    }
+
+    let groups = ctx.remaining_accounts;
+    if groups.is_empty() || groups.len() % BATCH_COMMIT_ACCOUNTS != 0 {
+        return err!(M0OracleError::InvalidBatch);
+    }
+    let count = groups.len() / BATCH_COMMIT_ACCOUNTS;
+    if count > MAX_BATCH_MARKETS {
+        return err!(M0OracleError::BatchTooLarge);
+    }
+
+    let clock = Clock::get()?;
+    let now_ms = unix_ms(&clock);
+    let now = clock.slot;
+    let reveal_after = now.saturating_add(reveal_delay_slots.unwrap_or(cfg.default_reveal_delay_slots));
+    let deadline = reveal_after.saturating_add(cfg.max_reveal_delay_slots);
+
+    let committer = ctx.accounts.committer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let fee_accounts = FeeAccounts::from_optional(
+        ctx.accounts.fee_router.as_ref(),
+        ctx.accounts.fee_vault.as_ref(),
+        ctx.accounts.fee_ledger.as_ref(),
+        ctx.accounts.fee_router_program.as_ref(),
+        &ctx.accounts.system_program,
+    );
+    let mut fee_total: u64 = 0;
+
+    for (i, g) in groups.chunks(BATCH_COMMIT_ACCOUNTS).enumerate() {
+        let (market_info, epoch_info, commit_info) = (&g[0], &g[1], &g[2]);
+        if groups[..i * BATCH_COMMIT_ACCOUNTS].chunks(BATCH_COMMIT_ACCOUNTS).any(|p| p[0].key == market_info.key) {
+            return err!(M0OracleError::InvalidBatch);
+        }
+
+        let m: Market = load(market_info)?;
+        require_pda(market_info, &[MARKET_SEED, m.market_id.as_bytes()])?;
+        require_role(cfg, &committer.key(), ctx.accounts.role.as_deref(), Role::Publisher, Some(&market_info.key()), Some(&m.domain))?;
+        if !m.active {
+            return err!(M0OracleError::MarketNotActive);
+        }
+
+        let mut e: Epoch = load(epoch_info)?;
+        require_pda(epoch_info, &[EPOCH_SEED, market_info.key.as_ref(), &e.epoch_id.to_le_bytes()])?;
+        e.require_accepting_commits(now_ms)?;
+
+        if let Some(fee_vault) = charge_publish_fee(&committer, fee_accounts.as_ref(), m.publish_fee_lamports)? {
+            fee_total = fee_total.saturating_add(m.publish_fee_lamports);
+            emit!(PublishFeePaid {
+                market: market_info.key(),
+                payer: committer.key(),
+                fee_vault,
+                amount_lamports: m.publish_fee_lamports,
+                paid_at_slot: now,
+            });
+        }
+
+        let bump = require_pda(commit_info, &[COMMIT_SEED, epoch_info.key.as_ref(), committer.key.as_ref()])?;
+        create_pda(&committer, commit_info, &system_program, CommitRecord::LEN, &[COMMIT_SEED, epoch_info.key.as_ref(), committer.key.as_ref(), &[bump]])?;
+
+        let c = CommitRecord {
+            market: market_info.key(),
+            epoch: epoch_info.key(),
+            committer: committer.key(),
+            commit_hash,
+            reveal_after_slot: reveal_after,
+            reveal_deadline_slot: deadline,
+            revealed: false,
+            expired: false,
+            has_chunked: false,
+            has_submission: false,
+            bump,
+        };
+        store(commit_info, &c)?;
+
+        e.commit_count = e.commit_count.saturating_add(1);
+        e.open_commits = e.open_commits.saturating_add(1);
+        store(epoch_info, &e)?;
+
+        emit!(PredictionCommitted {
+            market: c.market,
+            epoch: c.epoch,
+            committer: c.committer,
+            commit_hash,
+            reveal_after_slot: reveal_after,
+            reveal_deadline_slot: deadline,
+        });
+    }
+
+    emit!(BatchCommitted {
+        committer: committer.key(),
+        commit_hash,
+        market_count: count as u8,
+        fee_lamports: fee_total,
+        committed_at_slot: now,
+    });
+
+    Ok(())
+}
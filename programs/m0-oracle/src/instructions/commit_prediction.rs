@@ -7,6 +7,14 @@ use crate::state::commit::CommitRecord;
 use crate::state::config::ProtocolConfig;
 use crate::state::epoch::Epoch;
 use crate::state::market::Market;
+use crate::state::role::{Role, RoleAssignment};
+use crate::utils::access::require_role;
+use crate::utils::fees::{charge_publish_fee, FeeAccounts};
+use crate::utils::time::unix_ms;
+use m0_fee_router::program::M0FeeRouter;
+use m0_fee_router::state::ledger::{FeeLedger, LEDGER_SEED, SOL_LEDGER_MINT};
+use m0_fee_router::state::router::{Router, ROUTER_SEED};
+use m0_fee_router::state::vaults::{FeeVault, VAULT_SEED};
 
 #[derive(Accounts)]
 pub struct CommitPrediction<'info> {
@@ -19,6 +27,9 @@ pub struct CommitPrediction<'info> {
     )]
     pub config: Account<'info, ProtocolConfig>,
 
+    // Publisher RoleAssignment of the signer; omit when the signer is the protocol authority.
+    pub role: Option<Account<'info, RoleAssignment>>,
+
     #[account(
         seeds = [MARKET_SEED, market.market_id.as_bytes()],
         bump = market.bump
@@ -41,6 +52,33 @@ pub struct CommitPrediction<'info> {
     )]
     pub commit: Account<'info, CommitRecord>,
 
+    // m0-fee-router accounts for the publish fee, paid into the native SOL ledger.
+    // Only required when a publish fee is charged.
+    #[account(
+        seeds = [ROUTER_SEED],
+        bump = fee_router.bump,
+        seeds::program = m0_fee_router::ID
+    )]
+    pub fee_router: Option<Account<'info, Router>>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED],
+        bump = fee_vault.bump,
+        seeds::program = m0_fee_router::ID
+    )]
+    pub fee_vault: Option<Account<'info, FeeVault>>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, SOL_LEDGER_MINT.as_ref()],
+        bump = fee_ledger.bump,
+        seeds::program = m0_fee_router::ID
+    )]
+    pub fee_ledger: Option<Account<'info, FeeLedger>>,
+
+    pub fee_router_program: Option<Program<'info, M0FeeRouter>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -50,16 +88,38 @@ pub fn handler(ctx: Context<CommitPrediction>, commit_hash: [u8; 32], reveal_del
         return err!(M0OracleError::Paused);
     }
 
+    let m = &ctx.accounts.market;
+    require_role(cfg, &ctx.accounts.committer.key(), ctx.accounts.role.as_deref(), Role::Publisher, Some(&m.key()), Some(&m.domain))?;
+
     if !ctx.accounts.market.active {
         return err!(M0OracleError::MarketNotActive);
     }
-    if !ctx.accounts.epoch.open {
-        return err!(M0OracleError::EpochNotOpen);
+
+    let clock = Clock::get()?;
+    ctx.accounts.epoch.require_accepting_commits(unix_ms(&clock))?;
+
+    let fee = ctx.accounts.market.publish_fee_lamports;
+    let fee_accounts = FeeAccounts::from_optional(
+        ctx.accounts.fee_router.as_ref(),
+        ctx.accounts.fee_vault.as_ref(),
+        ctx.accounts.fee_ledger.as_ref(),
+        ctx.accounts.fee_router_program.as_ref(),
+        &ctx.accounts.system_program,
+    );
+    if let Some(fee_vault) = charge_publish_fee(&ctx.accounts.committer.to_account_info(), fee_accounts.as_ref(), fee)? {
+        emit!(PublishFeePaid {
+            market: ctx.accounts.market.key(),
+            payer: ctx.accounts.committer.key(),
+            fee_vault,
+            amount_lamports: fee,
+            paid_at_slot: clock.slot,
+        });
     }
 
     let delay = reveal_delay_slots.unwrap_or(cfg.default_reveal_delay_slots);
-    let now = Clock::get()?.slot;
+    let now = clock.slot;
     let reveal_after = now.saturating_add(delay);
+    let deadline = reveal_after.saturating_add(cfg.max_reveal_delay_slots);
 
     let c = &mut ctx.accounts.commit;
     c.market = ctx.accounts.market.key();
@@ -67,7 +127,11 @@ pub fn handler(ctx: Context<CommitPrediction>, commit_hash: [u8; 32], reveal_del
     c.committer = ctx.accounts.committer.key();
     c.commit_hash = commit_hash;
     c.reveal_after_slot = reveal_after;
+    c.reveal_deadline_slot = deadline;
     c.revealed = false;
+    c.expired = false;
+    c.has_chunked = false;
+    c.has_submission = false;
     c.bump = *ctx.bumps.get("commit").unwrap();
 
     emit!(PredictionCommitted {
@@ -76,7 +140,12 @@ pub fn handler(ctx: Context<CommitPrediction>, commit_hash: [u8; 32], reveal_del
         committer: c.committer,
         commit_hash: c.commit_hash,
         reveal_after_slot: reveal_after,
+        reveal_deadline_slot: deadline,
     });
 
+    let e = &mut ctx.accounts.epoch;
+    e.commit_count = e.commit_count.saturating_add(1);
+    e.open_commits = e.open_commits.saturating_add(1);
+
     Ok(())
 }
@@ -5,6 +5,9 @@ use crate::events::*;
 use crate::error::M0OracleError;
 use crate::state::config::ProtocolConfig;
 use crate::state::market::{Market, Domain};
+use crate::state::role::{Role, RoleAssignment};
+use crate::utils::access::require_role;
+use m0_registry::state::metadata::{MarketMetadata, MARKET_META_SEED};
 
 #[derive(Accounts)]
 #[instruction(market_id: String)]
@@ -15,11 +18,13 @@ pub struct CreateMarket<'info> {
     #[account(
         mut,
         seeds = [PROTOCOL_SEED],
-        bump = config.bump,
-        has_one = authority @ M0OracleError::Unauthorized
+        bump = config.bump
     )]
     pub config: Account<'info, ProtocolConfig>,
 
+    // RoleAssignment of the signer; omit when the signer is the protocol authority.
+    pub role: Option<Account<'info, RoleAssignment>>,
+
     #[account(
         init,
         payer = authority,
@@ -29,16 +34,38 @@ pub struct CreateMarket<'info> {
     )]
     pub market: Account<'info, Market>,
 
+    // The market must already be published and active in m0-registry.
+    #[account(
+        seeds = [MARKET_META_SEED, market_id.as_bytes()],
+        bump = registry_meta.bump,
+        seeds::program = m0_registry::ID
+    )]
+    pub registry_meta: Account<'info, MarketMetadata>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<CreateMarket>, market_id: String, domain: Domain, outcomes: Vec<String>, active: bool) -> Result<()> {
+#[allow(clippy::too_many_arguments)]
+pub fn handler(ctx: Context<CreateMarket>, market_id: String, domain: Domain, outcomes: Vec<String>, active: bool, epoch_window_ms: u64, publish_cadence_ms: u64, tier_policy: String) -> Result<()> {
     let cfg = &mut ctx.accounts.config;
     if cfg.paused {
         return err!(M0OracleError::Paused);
     }
 
+    let market_key = ctx.accounts.market.key();
+    require_role(cfg, &ctx.accounts.authority.key(), ctx.accounts.role.as_deref(), Role::MarketOperator, Some(&market_key), Some(&domain))?;
+
     Market::validate_ids(&market_id, &outcomes)?;
+    Market::validate_cadence(epoch_window_ms, publish_cadence_ms)?;
+    if tier_policy.len() > MAX_TIER_POLICY_LEN {
+        return err!(M0OracleError::InvalidParameter);
+    }
+
+    let meta = &ctx.accounts.registry_meta;
+    if !meta.is_active() {
+        return err!(M0OracleError::RegistryMarketInactive);
+    }
+    Market::validate_registry(meta, domain, &outcomes, publish_cadence_ms, &tier_policy)?;
 
     let m = &mut ctx.accounts.market;
     m.market_id = market_id.clone();
@@ -46,7 +73,15 @@ pub fn handler(ctx: Context<CreateMarket>, market_id: String, domain: Domain, ou
     m.active = active;
     m.outcomes = outcomes;
     m.current_epoch_id = 0;
+    m.epoch_window_ms = epoch_window_ms;
+    m.publish_cadence_ms = publish_cadence_ms;
+    m.tier_policy = tier_policy;
+    m.registry_updated_at_slot = meta.updated_at_slot;
     m.last_sequence = 0;
+    m.min_publishers = 0;
+    m.merkle_reveals = false;
+    m.publish_fee_lamports = 0;
+    m.resolved = false;
     m.bump = *ctx.bumps.get("market").unwrap();
 
     cfg.next_market_nonce = cfg.next_market_nonce.saturating_add(1);
@@ -54,6 +89,8 @@ pub fn handler(ctx: Context<CreateMarket>, market_id: String, domain: Domain, ou
     emit!(MarketCreated {
         market: m.key(),
         market_id,
+        epoch_window_ms,
+        publish_cadence_ms,
         created_at_slot: Clock::get()?.slot,
     });
 
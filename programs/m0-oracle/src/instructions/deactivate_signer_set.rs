@@ -0,0 +1,78 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::M0OracleError;
+use crate::events::*;
+use crate::state::config::ProtocolConfig;
+use crate::state::signer_set::SignerSet;
+
+// Retires a signer set immediately. Used to end an overlap window early, to cancel a
+// scheduled rotation (rollback) or to revoke a compromised set. Not blocked by pause.
+
+#[derive(Accounts)]
+pub struct DeactivateSignerSet<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROTOCOL_SEED],
+        bump = config.bump,
+        has_one = authority @ M0OracleError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [SIGNER_SET_SEED, &signer_set.signer_set_id.to_le_bytes()],
+        bump = signer_set.bump
+    )]
+    pub signer_set: Account<'info, SignerSet>,
+
+    // Current set; required when cancelling a scheduled rotation so its expiry is lifted.
+    #[account(
+        mut,
+        seeds = [SIGNER_SET_SEED, &current.signer_set_id.to_le_bytes()],
+        bump = current.bump
+    )]
+    pub current: Option<Account<'info, SignerSet>>,
+}
+
+pub fn handler(ctx: Context<DeactivateSignerSet>) -> Result<()> {
+    let cfg = &mut ctx.accounts.config;
+    let ss = &mut ctx.accounts.signer_set;
+    if !ss.active {
+        return err!(M0OracleError::SignerSetNotActive);
+    }
+
+    let now = Clock::get()?.slot;
+    ss.active = false;
+    ss.deactivated_at_slot = now;
+
+    let was_current = ss.signer_set_id == cfg.active_signer_set_id;
+    if was_current {
+        cfg.active_signer_set_id = 0;
+    }
+    if ss.signer_set_id == cfg.resolution_signer_set_id {
+        cfg.resolution_signer_set_id = 0;
+    }
+
+    if ss.signer_set_id == cfg.pending_signer_set_id {
+        cfg.pending_signer_set_id = 0;
+        if cfg.active_signer_set_id != 0 {
+            let cur = ctx.accounts.current.as_mut().ok_or(M0OracleError::InvalidParameter)?;
+            if cur.signer_set_id != cfg.active_signer_set_id {
+                return err!(M0OracleError::SignerSetMismatch);
+            }
+            cur.expires_at_slot = 0;
+        }
+    }
+
+    emit!(SignerSetDeactivated {
+        signer_set: ss.key(),
+        signer_set_id: ss.signer_set_id,
+        was_current,
+        deactivated_at_slot: now,
+    });
+
+    Ok(())
+}
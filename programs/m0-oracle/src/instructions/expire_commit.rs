@@ -0,0 +1,61 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::M0OracleError;
+use crate::events::*;
+use crate::state::commit::CommitRecord;
+use crate::state::epoch::Epoch;
+
+// Permissionless: anyone may record a missed reveal once the deadline has passed.
+// The expired CommitRecord is the on-chain evidence used by availability disputes.
+
+#[derive(Accounts)]
+pub struct ExpireCommit<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [EPOCH_SEED, epoch.market.as_ref(), &epoch.epoch_id.to_le_bytes()],
+        bump = epoch.bump
+    )]
+    pub epoch: Account<'info, Epoch>,
+
+    #[account(
+        mut,
+        seeds = [COMMIT_SEED, epoch.key().as_ref(), commit.committer.as_ref()],
+        bump = commit.bump
+    )]
+    pub commit: Account<'info, CommitRecord>,
+}
+
+pub fn handler(ctx: Context<ExpireCommit>) -> Result<()> {
+    let c = &mut ctx.accounts.commit;
+    if c.revealed {
+        return err!(M0OracleError::CommitAlreadyRevealed);
+    }
+    if c.expired {
+        return err!(M0OracleError::CommitNotSettled);
+    }
+
+    let now = Clock::get()?.slot;
+    if now <= c.reveal_deadline_slot {
+        return err!(M0OracleError::RevealDeadlineNotReached);
+    }
+
+    c.expired = true;
+
+    let e = &mut ctx.accounts.epoch;
+    e.missed_reveals = e.missed_reveals.saturating_add(1);
+
+    emit!(CommitExpired {
+        commit: c.key(),
+        market: c.market,
+        epoch: c.epoch,
+        committer: c.committer,
+        commit_hash: c.commit_hash,
+        reveal_deadline_slot: c.reveal_deadline_slot,
+        expired_at_slot: now,
+    });
+
+    Ok(())
+}
@@ -1,35 +1,75 @@
 
 use anchor_lang::prelude::*;
+use crate::constants::*;
 use crate::error::M0OracleError;
 use crate::events::*;
 use crate::state::config::ProtocolConfig;
 use crate::state::epoch::Epoch;
 use crate::state::market::Market;
+use crate::state::prediction::LatestPrediction;
+use crate::state::submission::PublisherSubmission;
+use crate::utils::aggregate::aggregate_outcomes;
+use crate::utils::hashing::hash_aggregate;
+use crate::utils::time::unix_ms;
+
+// In aggregation mode every PublisherSubmission of the epoch must be passed in
+// remaining_accounts (count is checked against epoch.submission_count, so none can
+// be left out). Below quorum the epoch still finalizes but LatestPrediction is untouched.
+// Permissionless once window_end_ms + config.finalization_delay_ms has passed.
 
 #[derive(Accounts)]
 pub struct FinalizeEpoch<'info> {
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub payer: Signer<'info>,
 
     #[account(
-        seeds = [crate::constants::PROTOCOL_SEED],
-        bump = config.bump,
-        has_one = authority @ M0OracleError::Unauthorized
+        seeds = [PROTOCOL_SEED],
+        bump = config.bump
     )]
     pub config: Account<'info, ProtocolConfig>,
 
     #[account(
-        seeds = [crate::constants::MARKET_SEED, market.market_id.as_bytes()],
+        seeds = [MARKET_SEED, market.market_id.as_bytes()],
         bump = market.bump
     )]
     pub market: Account<'info, Market>,
 
     #[account(
         mut,
-        seeds = [crate::constants::EPOCH_SEED, market.key().as_ref(), &epoch.epoch_id.to_le_bytes()],
+        seeds = [EPOCH_SEED, market.key().as_ref(), &epoch.epoch_id.to_le_bytes()],
         bump = epoch.bump
     )]
     pub epoch: Account<'info, Epoch>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = LatestPrediction::LEN,
+        seeds = [PREDICTION_SEED, market.key().as_ref()],
+        bump
+    )]
+    pub prediction: Account<'info, LatestPrediction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+fn load_submissions(infos: &[AccountInfo], epoch: &Pubkey) -> Result<Vec<PublisherSubmission>> {
+    let mut subs: Vec<PublisherSubmission> = Vec::with_capacity(infos.len());
+    for info in infos {
+        if info.owner != &crate::ID {
+            return err!(M0OracleError::InvalidSubmission);
+        }
+        let data = info.try_borrow_data()?;
+        let s = PublisherSubmission::try_deserialize(&mut &data[..])?;
+        let expected = Pubkey::create_program_address(&[SUBMISSION_SEED, epoch.as_ref(), s.publisher.as_ref(), &[s.bump]], &crate::ID)
+            .map_err(|_| M0OracleError::InvalidSubmission)?;
+        if expected != info.key() || s.epoch != *epoch || subs.iter().any(|o| o.publisher == s.publisher) {
+            return err!(M0OracleError::InvalidSubmission);
+        }
+        subs.push(s);
+    }
+    subs.sort_by_key(|s| s.publisher);
+    Ok(subs)
 }
 
 pub fn handler(ctx: Context<FinalizeEpoch>) -> Result<()> {
@@ -38,17 +78,53 @@ pub fn handler(ctx: Context<FinalizeEpoch>) -> Result<()> {
         return err!(M0OracleError::Paused);
     }
 
+    let m = &ctx.accounts.market;
     let e = &mut ctx.accounts.epoch;
     if !e.open {
         return err!(M0OracleError::EpochNotOpen);
     }
 
+    let clock = Clock::get()?;
+    if unix_ms(&clock) < e.window_end_ms.saturating_add(cfg.finalization_delay_ms) {
+        return err!(M0OracleError::EpochWindowNotElapsed);
+    }
+
+    let now = clock.slot;
     e.open = false;
-    e.finalized_at_slot = Clock::get()?.slot;
+    e.finalized_at_slot = now;
+
+    if m.min_publishers > 0 || e.submission_count > 0 {
+        if ctx.remaining_accounts.len() != e.submission_count as usize {
+            return err!(M0OracleError::InvalidSubmission);
+        }
+        let subs = load_submissions(ctx.remaining_accounts, &e.key())?;
+        let quorum_met = subs.len() >= m.min_publishers.max(1) as usize;
+
+        let hashes: Vec<[u8; 32]> = subs.iter().map(|s| s.bundle_hash).collect();
+        let aggregate_hash = hash_aggregate(&hashes);
+
+        if quorum_met {
+            let outcomes = aggregate_outcomes(&subs, m.outcomes.len());
+            let p = &mut ctx.accounts.prediction;
+            p.market = m.key();
+            p.apply_aggregate(e.epoch_id, e.publish_sequence, &subs, aggregate_hash, outcomes, now);
+        }
+        ctx.accounts.prediction.bump = *ctx.bumps.get("prediction").unwrap();
+
+        emit!(EpochAggregated {
+            epoch: e.key(),
+            market: m.key(),
+            epoch_id: e.epoch_id,
+            publisher_count: subs.len() as u8,
+            min_publishers: m.min_publishers,
+            quorum_met,
+            aggregate_hash,
+        });
+    }
 
     emit!(EpochFinalized {
         epoch: e.key(),
-        market: ctx.accounts.market.key(),
+        market: m.key(),
         epoch_id: e.epoch_id,
         finalized_at_slot: e.finalized_at_slot,
     });
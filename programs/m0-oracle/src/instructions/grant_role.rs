@@ -0,0 +1,68 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::M0OracleError;
+use crate::events::*;
+use crate::state::config::ProtocolConfig;
+use crate::state::role::{Role, RoleAssignment, RoleScope};
+
+#[derive(Accounts)]
+#[instruction(role: Role, grantee: Pubkey, scope: RoleScope)]
+pub struct GrantRole<'info> {
+    #[account(mut)]
+    pub granter: Signer<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    // Admin assignment of the granter; omit when the granter is the protocol authority.
+    pub granter_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        init,
+        payer = granter,
+        space = RoleAssignment::LEN,
+        seeds = [ROLE_SEED, &[role as u8], grantee.as_ref(), &scope.seed_tag(), &scope.seed_payload()],
+        bump
+    )]
+    pub assignment: Account<'info, RoleAssignment>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<GrantRole>, role: Role, grantee: Pubkey, scope: RoleScope, weight: Option<u16>) -> Result<()> {
+    let cfg = &ctx.accounts.config;
+    let granter = ctx.accounts.granter.key();
+
+    // Only the protocol authority hands out Admin; global admins manage the other roles.
+    if granter != cfg.authority {
+        let admin = ctx.accounts.granter_role.as_deref().ok_or(M0OracleError::Unauthorized)?;
+        if admin.grantee != granter || admin.role != Role::Admin || admin.scope != RoleScope::Global || role == Role::Admin {
+            return err!(M0OracleError::Unauthorized);
+        }
+    }
+
+    let a = &mut ctx.accounts.assignment;
+    a.role = role;
+    a.grantee = grantee;
+    a.scope = scope;
+    a.granted_by = granter;
+    a.granted_at_slot = Clock::get()?.slot;
+    a.weight = weight.unwrap_or(DEFAULT_PUBLISHER_WEIGHT).max(1);
+    a.bump = *ctx.bumps.get("assignment").unwrap();
+
+    emit!(RoleGranted {
+        assignment: a.key(),
+        role,
+        grantee,
+        scope,
+        granted_by: granter,
+        granted_at_slot: a.granted_at_slot,
+        weight: a.weight,
+    });
+
+    Ok(())
+}
@@ -22,7 +22,7 @@ pub struct InitProtocol<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<InitProtocol>, default_reveal_delay_slots: Option<u64>) -> Result<()> {
+pub fn handler(ctx: Context<InitProtocol>, default_reveal_delay_slots: Option<u64>, max_reveal_delay_slots: Option<u64>, max_signer_overlap_slots: Option<u64>, finalization_delay_ms: Option<u64>) -> Result<()> {
     let cfg = &mut ctx.accounts.config;
     if cfg.initialized {
         return err!(M0OracleError::AlreadyInitialized);
@@ -30,10 +30,19 @@ pub fn handler(ctx: Context<InitProtocol>, default_reveal_delay_slots: Option<u6
 
     cfg.initialized = true;
     cfg.authority = ctx.accounts.authority.key();
+    cfg.pending_authority = None;
     cfg.paused = false;
     cfg.next_market_nonce = 0;
     cfg.next_signer_set_id = 1;
     cfg.default_reveal_delay_slots = default_reveal_delay_slots.unwrap_or(DEFAULT_REVEAL_DELAY_SLOTS);
+    cfg.max_reveal_delay_slots = max_reveal_delay_slots.unwrap_or(DEFAULT_MAX_REVEAL_DELAY_SLOTS);
+    cfg.active_signer_set_id = 0;
+    cfg.pending_signer_set_id = 0;
+    cfg.max_signer_overlap_slots = max_signer_overlap_slots.unwrap_or(DEFAULT_MAX_SIGNER_OVERLAP_SLOTS);
+    cfg.dispute_window_slots = DEFAULT_DISPUTE_WINDOW_SLOTS;
+    cfg.min_dispute_bond_lamports = DEFAULT_MIN_DISPUTE_BOND_LAMPORTS;
+    cfg.finalization_delay_ms = finalization_delay_ms.unwrap_or(DEFAULT_FINALIZATION_DELAY_MS);
+    cfg.resolution_signer_set_id = 0;
     cfg.bump = *ctx.bumps.get("config").unwrap();
 
     emit!(ProtocolInitialized {
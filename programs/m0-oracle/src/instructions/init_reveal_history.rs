@@ -0,0 +1,48 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::events::*;
+use crate::state::history::RevealHistory;
+use crate::state::market::Market;
+
+// Permissionless: creates the per-market reveal ring buffer. Reveals require it.
+
+#[derive(Accounts)]
+pub struct InitRevealHistory<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [MARKET_SEED, market.market_id.as_bytes()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = RevealHistory::LEN,
+        seeds = [HISTORY_SEED, market.key().as_ref()],
+        bump
+    )]
+    pub history: AccountLoader<'info, RevealHistory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitRevealHistory>) -> Result<()> {
+    let mut h = ctx.accounts.history.load_init()?;
+    h.market = ctx.accounts.market.key();
+    h.total_reveals = 0;
+    h.head = 0;
+    h.count = 0;
+    h.bump = *ctx.bumps.get("history").unwrap();
+
+    emit!(RevealHistoryInitialized {
+        market: h.market,
+        history: ctx.accounts.history.key(),
+        capacity: REVEAL_HISTORY_CAPACITY as u32,
+    });
+
+    Ok(())
+}
@@ -1,11 +1,33 @@
 
+pub mod accept_authority;
+pub mod activate_signer_set;
+pub mod cancel_authority_transfer;
+pub mod close_commit;
+pub mod close_dispute;
+pub mod close_epoch;
+pub mod commit_batch;
 pub mod commit_prediction;
 pub mod create_market;
+pub mod deactivate_signer_set;
+pub mod expire_commit;
 pub mod finalize_epoch;
+pub mod grant_role;
 pub mod init_protocol;
+pub mod init_reveal_history;
+pub mod open_dispute;
 pub mod open_epoch;
 pub mod pause_resume;
+pub mod propose_authority;
+pub mod resolve_dispute;
+pub mod reveal_batch;
+pub mod reveal_chunk;
+pub mod reveal_merkle_root;
 pub mod reveal_prediction;
+pub mod resolve_market;
+pub mod revoke_role;
 pub mod rotate_signer_set;
+pub mod set_dispute_params;
+pub mod set_resolution_signer_set;
+pub mod supersede_epoch;
+pub mod sync_market;
 pub mod update_market;
-pub mod upgrade_admin;
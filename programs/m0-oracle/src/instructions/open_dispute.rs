@@ -0,0 +1,129 @@
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::constants::*;
+use crate::error::M0OracleError;
+use crate::events::*;
+use crate::state::config::ProtocolConfig;
+use crate::state::dispute::{CorrectionAction, DisputeReason, DisputeRecord, DisputeState};
+use crate::state::epoch::{Epoch, OutputStatus};
+use crate::state::market::Market;
+use crate::state::prediction::LatestPrediction;
+
+// Permissionless. Allowed while paused so incidents can still be reported.
+
+#[derive(Accounts)]
+#[instruction(reason: DisputeReason, bundle_hash: [u8; 32])]
+pub struct OpenDispute<'info> {
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        seeds = [MARKET_SEED, market.market_id.as_bytes()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        seeds = [EPOCH_SEED, market.key().as_ref(), &epoch.epoch_id.to_le_bytes()],
+        bump = epoch.bump
+    )]
+    pub epoch: Account<'info, Epoch>,
+
+    #[account(
+        init,
+        payer = reporter,
+        space = DisputeRecord::LEN,
+        seeds = [DISPUTE_SEED, epoch.key().as_ref(), bundle_hash.as_ref()],
+        bump
+    )]
+    pub dispute: Account<'info, DisputeRecord>,
+
+    // Required once the epoch has a reveal, so consumers see the dispute.
+    #[account(
+        mut,
+        seeds = [PREDICTION_SEED, market.key().as_ref()],
+        bump = prediction.bump
+    )]
+    pub prediction: Option<Account<'info, LatestPrediction>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<OpenDispute>, reason: DisputeReason, bundle_hash: [u8; 32], evidence_hash: [u8; 32], bond_lamports: u64) -> Result<()> {
+    let cfg = &ctx.accounts.config;
+    let e = &mut ctx.accounts.epoch;
+
+    if matches!(e.output_status, OutputStatus::Retracted | OutputStatus::Superseded) {
+        return err!(M0OracleError::OutputAlreadyCorrected);
+    }
+
+    let now = Clock::get()?.slot;
+    if e.finalized_at_slot != 0 && now > e.finalized_at_slot.saturating_add(cfg.dispute_window_slots) {
+        return err!(M0OracleError::DisputeWindowClosed);
+    }
+    if bond_lamports < cfg.min_dispute_bond_lamports {
+        return err!(M0OracleError::DisputeBondTooLow);
+    }
+
+    if bond_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.reporter.to_account_info(),
+                    to: ctx.accounts.dispute.to_account_info(),
+                },
+            ),
+            bond_lamports,
+        )?;
+    }
+
+    let d = &mut ctx.accounts.dispute;
+    d.market = ctx.accounts.market.key();
+    d.epoch = e.key();
+    d.epoch_id = e.epoch_id;
+    d.bundle_hash = bundle_hash;
+    d.reason = reason;
+    d.reporter = ctx.accounts.reporter.key();
+    d.evidence_hash = evidence_hash;
+    d.bond_lamports = bond_lamports;
+    d.opened_at_slot = now;
+    d.state = DisputeState::Open;
+    d.resolver = Pubkey::default();
+    d.resolved_at_slot = 0;
+    d.action = CorrectionAction::None;
+    d.corrective_bundle_hash = [0u8; 32];
+    d.bump = *ctx.bumps.get("dispute").unwrap();
+
+    e.open_disputes = e.open_disputes.saturating_add(1);
+    e.output_status = OutputStatus::Disputed;
+
+    match ctx.accounts.prediction.as_mut() {
+        Some(p) => p.mark_status(e.epoch_id, OutputStatus::Disputed, [0u8; 32]),
+        None if e.publish_sequence == 0 => {}
+        None => return err!(M0OracleError::InvalidParameter),
+    }
+
+    emit!(DisputeOpened {
+        dispute: d.key(),
+        market: d.market,
+        epoch: d.epoch,
+        epoch_id: d.epoch_id,
+        bundle_hash,
+        reason,
+        reporter: d.reporter,
+        evidence_hash,
+        bond_lamports,
+        opened_at_slot: now,
+    });
+
+    Ok(())
+}
@@ -4,18 +4,22 @@ use crate::constants::*;
 use crate::events::*;
 use crate::error::M0OracleError;
 use crate::state::config::ProtocolConfig;
-use crate::state::epoch::Epoch;
+use crate::state::epoch::{Epoch, OutputStatus};
 use crate::state::market::Market;
+use crate::utils::time::{epoch_bounds, unix_ms};
+
+// Permissionless: epoch_id must be the index of the window containing the cluster
+// clock (docs/protocol-spec/epoch-rounding.md §4). Windows nobody opened are skipped.
 
 #[derive(Accounts)]
+#[instruction(epoch_id: u64)]
 pub struct OpenEpoch<'info> {
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub payer: Signer<'info>,
 
     #[account(
         seeds = [PROTOCOL_SEED],
-        bump = config.bump,
-        has_one = authority @ M0OracleError::Unauthorized
+        bump = config.bump
     )]
     pub config: Account<'info, ProtocolConfig>,
 
@@ -28,9 +32,9 @@ pub struct OpenEpoch<'info> {
 
     #[account(
         init,
-        payer = authority,
+        payer = payer,
         space = Epoch::LEN,
-        seeds = [EPOCH_SEED, market.key().as_ref(), &market.current_epoch_id.saturating_add(1).to_le_bytes()],
+        seeds = [EPOCH_SEED, market.key().as_ref(), &epoch_id.to_le_bytes()],
         bump
     )]
     pub epoch: Account<'info, Epoch>,
@@ -38,7 +42,7 @@ pub struct OpenEpoch<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<OpenEpoch>) -> Result<()> {
+pub fn handler(ctx: Context<OpenEpoch>, epoch_id: u64) -> Result<()> {
     let cfg = &ctx.accounts.config;
     if cfg.paused {
         return err!(M0OracleError::Paused);
@@ -49,23 +53,44 @@ pub fn handler(ctx: Context<OpenEpoch>) -> Result<()> {
         return err!(M0OracleError::MarketNotActive);
     }
 
-    let next_epoch_id = m.current_epoch_id.saturating_add(1);
+    let clock = Clock::get()?;
+    let (index, window_start_ms, window_end_ms) = epoch_bounds(unix_ms(&clock), m.epoch_window_ms);
+    if epoch_id != index {
+        return err!(M0OracleError::EpochWindowMismatch);
+    }
+    // a closed epoch's PDA could otherwise be re-opened
+    if epoch_id <= m.current_epoch_id {
+        return err!(M0OracleError::EpochWindowStale);
+    }
 
     let e = &mut ctx.accounts.epoch;
     e.market = m.key();
-    e.epoch_id = next_epoch_id;
+    e.epoch_id = epoch_id;
+    e.window_start_ms = window_start_ms;
+    e.window_end_ms = window_end_ms;
     e.open = true;
-    e.opened_at_slot = Clock::get()?.slot;
+    e.opened_at_slot = clock.slot;
     e.finalized_at_slot = 0;
     e.publish_sequence = 0;
+    e.rent_payer = ctx.accounts.payer.key();
+    e.commit_count = 0;
+    e.open_commits = 0;
+    e.missed_reveals = 0;
+    e.submission_count = 0;
+    e.output_status = OutputStatus::Valid;
+    e.superseded_by = [0u8; 32];
+    e.open_disputes = 0;
+    e.accepted_disputes = 0;
     e.bump = *ctx.bumps.get("epoch").unwrap();
 
-    m.current_epoch_id = next_epoch_id;
+    m.current_epoch_id = epoch_id;
 
     emit!(EpochOpened {
         epoch: e.key(),
         market: m.key(),
         epoch_id: e.epoch_id,
+        window_start_ms,
+        window_end_ms,
         opened_at_slot: e.opened_at_slot,
     });
 
@@ -1,9 +1,10 @@
 
 use anchor_lang::prelude::*;
-use crate::error::M0OracleError;
 use crate::events::*;
 use crate::state::config::ProtocolConfig;
 use crate::constants::*;
+use crate::state::role::{Role, RoleAssignment};
+use crate::utils::access::require_role;
 
 #[derive(Accounts)]
 pub struct SetPaused<'info> {
@@ -13,13 +14,19 @@ pub struct SetPaused<'info> {
     #[account(
         mut,
         seeds = [PROTOCOL_SEED],
-        bump = config.bump,
-        has_one = authority @ M0OracleError::Unauthorized
+        bump = config.bump
     )]
     pub config: Account<'info, ProtocolConfig>,
+
+    // RoleAssignment of the signer; omit when the signer is the protocol authority.
+    pub role: Option<Account<'info, RoleAssignment>>,
 }
 
 pub fn handler(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    // Pausers may only pause; resuming needs an admin.
+    let required = if paused { Role::Pauser } else { Role::Admin };
+    require_role(&ctx.accounts.config, &ctx.accounts.authority.key(), ctx.accounts.role.as_deref(), required, None, None)?;
+
     let cfg = &mut ctx.accounts.config;
     cfg.paused = paused;
     emit!(PausedChanged { paused });
@@ -0,0 +1,39 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::M0OracleError;
+use crate::events::*;
+use crate::state::config::ProtocolConfig;
+
+// Step 1 of the authority handover. The new key takes over only after it signs
+// accept_authority; until then the current authority can cancel or re-propose.
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROTOCOL_SEED],
+        bump = config.bump,
+        has_one = authority @ M0OracleError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+}
+
+pub fn handler(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    let cfg = &mut ctx.accounts.config;
+    if new_authority == Pubkey::default() || new_authority == cfg.authority {
+        return err!(M0OracleError::InvalidParameter);
+    }
+
+    cfg.pending_authority = Some(new_authority);
+
+    emit!(AuthorityTransferProposed {
+        authority: cfg.authority,
+        pending_authority: new_authority,
+        proposed_at_slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
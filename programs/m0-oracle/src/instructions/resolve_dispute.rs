@@ -0,0 +1,123 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::M0OracleError;
+use crate::events::*;
+use crate::state::config::ProtocolConfig;
+use crate::state::dispute::{DisputeRecord, DisputeState};
+use crate::state::epoch::{Epoch, OutputStatus};
+use crate::state::market::Market;
+use crate::state::prediction::LatestPrediction;
+use crate::state::role::{Role, RoleAssignment};
+use crate::utils::access::require_role;
+use m0_fee_router::state::vaults::{FeeVault, VAULT_SEED};
+
+// Accepted: bond returned to the reporter; the output stays Disputed until
+// supersede_epoch applies a correction. Rejected: bond forfeited to the m0-fee-router
+// FeeVault (credited to the routes by route_fees, like slashed bonds); the output
+// returns to Valid once nothing else is pending.
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    pub resolver: Signer<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    // DisputeResolver RoleAssignment of the signer; omit when the signer is the protocol authority.
+    pub role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        seeds = [MARKET_SEED, market.market_id.as_bytes()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        seeds = [EPOCH_SEED, market.key().as_ref(), &epoch.epoch_id.to_le_bytes()],
+        bump = epoch.bump
+    )]
+    pub epoch: Account<'info, Epoch>,
+
+    #[account(
+        mut,
+        seeds = [DISPUTE_SEED, epoch.key().as_ref(), dispute.bundle_hash.as_ref()],
+        bump = dispute.bump
+    )]
+    pub dispute: Account<'info, DisputeRecord>,
+
+    #[account(
+        mut,
+        address = dispute.reporter @ M0OracleError::Unauthorized
+    )]
+    pub reporter: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [PREDICTION_SEED, market.key().as_ref()],
+        bump = prediction.bump
+    )]
+    pub prediction: Option<Account<'info, LatestPrediction>>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED],
+        bump = fee_vault.bump,
+        seeds::program = m0_fee_router::ID
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+}
+
+pub fn handler(ctx: Context<ResolveDispute>, accept: bool) -> Result<()> {
+    let m = &ctx.accounts.market;
+    require_role(&ctx.accounts.config, &ctx.accounts.resolver.key(), ctx.accounts.role.as_deref(), Role::DisputeResolver, Some(&m.key()), Some(&m.domain))?;
+
+    let d = &mut ctx.accounts.dispute;
+    if d.state != DisputeState::Open {
+        return err!(M0OracleError::DisputeNotOpen);
+    }
+
+    let now = Clock::get()?.slot;
+    d.state = if accept { DisputeState::Accepted } else { DisputeState::Rejected };
+    d.resolver = ctx.accounts.resolver.key();
+    d.resolved_at_slot = now;
+
+    // release the bond held on the dispute account
+    if d.bond_lamports > 0 {
+        let dest = if accept { ctx.accounts.reporter.to_account_info() } else { ctx.accounts.fee_vault.to_account_info() };
+        **d.to_account_info().try_borrow_mut_lamports()? -= d.bond_lamports;
+        **dest.try_borrow_mut_lamports()? += d.bond_lamports;
+    }
+
+    let e = &mut ctx.accounts.epoch;
+    e.open_disputes = e.open_disputes.saturating_sub(1);
+    if accept {
+        e.accepted_disputes = e.accepted_disputes.saturating_add(1);
+    } else if e.open_disputes == 0 && e.accepted_disputes == 0 && e.output_status == OutputStatus::Disputed {
+        e.output_status = OutputStatus::Valid;
+        match ctx.accounts.prediction.as_mut() {
+            Some(p) => p.mark_status(e.epoch_id, OutputStatus::Valid, [0u8; 32]),
+            None if e.publish_sequence == 0 => {}
+            None => return err!(M0OracleError::InvalidParameter),
+        }
+    }
+
+    emit!(DisputeResolved {
+        dispute: d.key(),
+        market: d.market,
+        epoch: d.epoch,
+        epoch_id: d.epoch_id,
+        bundle_hash: d.bundle_hash,
+        accepted: accept,
+        resolver: d.resolver,
+        evidence_hash: d.evidence_hash,
+        bond_lamports: d.bond_lamports,
+        resolved_at_slot: now,
+    });
+
+    Ok(())
+}
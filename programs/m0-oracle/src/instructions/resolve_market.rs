@@ -0,0 +1,114 @@
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as ix_sysvar;
+use crate::constants::*;
+use crate::error::M0OracleError;
+use crate::events::*;
+use crate::state::config::ProtocolConfig;
+use crate::state::market::Market;
+use crate::state::resolution::{MarketResolution, ResolutionOutcome};
+use crate::state::signer_set::{SignerSet, SignerSetPurpose};
+use crate::utils::hashing::hash_resolution_message;
+use crate::verify::signature::verify_threshold_signatures;
+
+// Records the final outcome of a market. Authorization is the threshold of the current
+// resolution signer set over hash_resolution_message (Ed25519Program ixs in this tx);
+// anyone may submit. The market is deactivated for good; the resolution PDA is
+// created once, so a market can't be resolved twice.
+
+#[derive(Accounts)]
+pub struct ResolveMarket<'info> {
+    #[account(mut)]
+    pub resolver: Signer<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, market.market_id.as_bytes()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        seeds = [SIGNER_SET_SEED, &signer_set.signer_set_id.to_le_bytes()],
+        bump = signer_set.bump
+    )]
+    pub signer_set: Account<'info, SignerSet>,
+
+    #[account(
+        init,
+        payer = resolver,
+        space = MarketResolution::LEN,
+        seeds = [RESOLUTION_SEED, market.key().as_ref()],
+        bump
+    )]
+    pub resolution: Account<'info, MarketResolution>,
+
+    /// CHECK: address is constrained to the instructions sysvar; parsed in verify::signature.
+    #[account(address = ix_sysvar::ID @ M0OracleError::InvalidInstructionsSysvar)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ResolveMarket>, outcome: ResolutionOutcome, resolution_source_hash: [u8; 32]) -> Result<()> {
+    let cfg = &ctx.accounts.config;
+    if cfg.paused {
+        return err!(M0OracleError::Paused);
+    }
+
+    let m = &mut ctx.accounts.market;
+    if m.resolved {
+        return err!(M0OracleError::MarketResolved);
+    }
+    let winning_outcome_id = match outcome {
+        ResolutionOutcome::Winner { outcome_index } => {
+            Some(m.outcomes.get(outcome_index as usize).ok_or(M0OracleError::InvalidOutcomeId)?.clone())
+        }
+        ResolutionOutcome::Void => None,
+    };
+
+    let now = Clock::get()?.slot;
+    let ss = &ctx.accounts.signer_set;
+    if ss.purpose != SignerSetPurpose::Resolution || ss.signer_set_id != cfg.resolution_signer_set_id {
+        return err!(M0OracleError::SignerSetMismatch);
+    }
+    if !ss.is_valid_at(now) {
+        return err!(M0OracleError::SignerSetNotActive);
+    }
+    SignerSet::validate(ss.threshold, ss.pubkeys.len())?;
+
+    let outcome_bytes = outcome.try_to_vec()?;
+    let msg = hash_resolution_message(&m.market_id, &outcome_bytes, &resolution_source_hash, ss.signer_set_id);
+    verify_threshold_signatures(&ctx.accounts.instructions.to_account_info(), &msg, &ss.pubkeys, ss.threshold)?;
+
+    let r = &mut ctx.accounts.resolution;
+    r.market = m.key();
+    r.outcome = outcome;
+    r.resolution_source_hash = resolution_source_hash;
+    r.signer_set_id = ss.signer_set_id;
+    r.resolution_slot = now;
+    r.resolver = ctx.accounts.resolver.key();
+    r.bump = *ctx.bumps.get("resolution").unwrap();
+
+    m.active = false;
+    m.resolved = true;
+
+    emit!(MarketResolved {
+        market: m.key(),
+        market_id: m.market_id.clone(),
+        resolution: r.key(),
+        outcome,
+        winning_outcome_id,
+        resolution_source_hash,
+        signer_set_id: ss.signer_set_id,
+        resolution_slot: now,
+    });
+
+    Ok(())
+}
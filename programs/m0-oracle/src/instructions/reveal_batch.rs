@@ -0,0 +1,211 @@
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as ix_sysvar;
+use crate::constants::*;
+use crate::error::M0OracleError;
+use crate::events::*;
+use crate::state::audit::AuditLog;
+use crate::state::commit::CommitRecord;
+use crate::state::config::ProtocolConfig;
+use crate::state::epoch::Epoch;
+use crate::state::history::{RevealHistory, RevealHistoryEntry};
+use crate::state::market::Market;
+use crate::state::prediction::LatestPrediction;
+use crate::state::reveal::BundleReveal;
+use crate::state::role::{Role, RoleAssignment};
+use crate::state::signer_set::SignerSet;
+use crate::utils::access::require_role;
+use crate::utils::accounts::{load, load_or_create, require_pda, store};
+use crate::utils::hashing::{hash_batch_signature_message, hash_bundle_content, hash_commit};
+use crate::verify::bundle::{require_publish_epoch, validate_market_reveal};
+use crate::verify::signature::verify_threshold_signatures;
+
+// Reveals a commit_batch bundle for every market it lists, atomically.
+// remaining_accounts holds BATCH_REVEAL_ACCOUNTS per bundle market, in bundle order:
+// [market, epoch, commit, audit, history, prediction], all mut. Audit and prediction
+// are created when missing. Signers sign hash_batch_signature_message once; aggregation
+// markets (min_publishers > 0) must use reveal_prediction.
+
+#[derive(Accounts)]
+pub struct RevealBatch<'info> {
+    #[account(mut)]
+    pub revealer: Signer<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    // Publisher RoleAssignment of the signer; omit when the signer is the protocol authority.
+    pub role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        seeds = [SIGNER_SET_SEED, &signer_set.signer_set_id.to_le_bytes()],
+        bump = signer_set.bump
+    )]
+    pub signer_set: Account<'info, SignerSet>,
+
+    /// CHECK: address is constrained to the instructions sysvar; parsed in verify::signature.
+    #[account(address = ix_sysvar::ID @ M0OracleError::InvalidInstructionsSysvar)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+struct Pending {
+    market: Market,
+    epoch: Epoch,
+    commit: CommitRecord,
+    sequence: u64,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, RevealBatch<'info>>, salt: [u8; 32], bundle_bytes: Vec<u8>) -> Result<()> {
+    let cfg = &ctx.accounts.config;
+    if cfg.paused {
+        return err!(M0OracleError::Paused);
+    }
+
+    // the canonical encoding is the Borsh form of BundleReveal, so decode it directly
+    let bundle = BundleReveal::try_from_slice(&bundle_bytes).map_err(|_| M0OracleError::BundleHashMismatch)?;
+    let count = bundle.markets.len();
+    if count > MAX_BATCH_MARKETS {
+        return err!(M0OracleError::BatchTooLarge);
+    }
+    let groups = ctx.remaining_accounts;
+    if count == 0 || groups.len() != count * BATCH_REVEAL_ACCOUNTS {
+        return err!(M0OracleError::InvalidBatch);
+    }
+
+    let content_hash = hash_bundle_content(&bundle_bytes);
+    let expected_commit = hash_commit(&content_hash, &salt);
+    let revealer = ctx.accounts.revealer.to_account_info();
+    let now = Clock::get()?.slot;
+
+    // 1) validate every market against its accounts before writing anything
+    let mut pending: Vec<Pending> = Vec::with_capacity(count);
+    for (i, (mr, g)) in bundle.markets.iter().zip(groups.chunks(BATCH_REVEAL_ACCOUNTS)).enumerate() {
+        if bundle.markets[..i].iter().any(|p| p.market_id == mr.market_id) {
+            return err!(M0OracleError::InvalidMarketId);
+        }
+        let (market_info, epoch_info, commit_info) = (&g[0], &g[1], &g[2]);
+
+        let market: Market = load(market_info)?;
+        require_pda(market_info, &[MARKET_SEED, market.market_id.as_bytes()])?;
+        require_role(cfg, &revealer.key(), ctx.accounts.role.as_deref(), Role::Publisher, Some(&market_info.key()), Some(&market.domain))?;
+        if !market.active {
+            return err!(M0OracleError::MarketNotActive);
+        }
+        if market.min_publishers > 0 {
+            return err!(M0OracleError::InvalidBatch);
+        }
+        if market.merkle_reveals {
+            return err!(M0OracleError::RevealModeMismatch);
+        }
+
+        let epoch: Epoch = load(epoch_info)?;
+        require_pda(epoch_info, &[EPOCH_SEED, market_info.key.as_ref(), &epoch.epoch_id.to_le_bytes()])?;
+        if !epoch.open {
+            return err!(M0OracleError::EpochNotOpen);
+        }
+
+        let commit: CommitRecord = load(commit_info)?;
+        require_pda(commit_info, &[COMMIT_SEED, epoch_info.key.as_ref(), revealer.key.as_ref()])?;
+        if commit.revealed {
+            return err!(M0OracleError::CommitAlreadyRevealed);
+        }
+        if now < commit.reveal_after_slot {
+            return err!(M0OracleError::RevealTooEarly);
+        }
+        if commit.expired || now > commit.reveal_deadline_slot {
+            return err!(M0OracleError::RevealDeadlinePassed);
+        }
+        if commit.commit_hash != expected_commit {
+            return err!(M0OracleError::RevealMismatch);
+        }
+
+        require_publish_epoch(bundle.publish_epoch_id, epoch.epoch_id)?;
+        validate_market_reveal(&market, epoch.epoch_id, mr)?;
+
+        let sequence = epoch.publish_sequence.saturating_add(1);
+        pending.push(Pending { market, epoch, commit, sequence });
+    }
+
+    // 2) one threshold signature over the bundle and every market's epoch and next sequence
+    let ss = &ctx.accounts.signer_set;
+    ss.require_usable(bundle.signer_set_id, now)?;
+    SignerSet::validate(ss.threshold, ss.pubkeys.len())?;
+
+    let targets: Vec<(Pubkey, Pubkey, u64)> = groups
+        .chunks(BATCH_REVEAL_ACCOUNTS)
+        .zip(&pending)
+        .map(|(g, p)| (g[0].key(), g[1].key(), p.sequence))
+        .collect();
+    let sig_msg = hash_batch_signature_message(&content_hash, bundle.signer_set_id, bundle.publish_epoch_id, &targets);
+    verify_threshold_signatures(&ctx.accounts.instructions.to_account_info(), &sig_msg, &ss.pubkeys, ss.threshold)?;
+
+    // 3) advance sequences and write audit, output and history per market
+    let system_program = ctx.accounts.system_program.to_account_info();
+    for ((mr, g), mut p) in bundle.markets.iter().zip(groups.chunks(BATCH_REVEAL_ACCOUNTS)).zip(pending) {
+        let (market_info, epoch_info, commit_info, audit_info, history_info, prediction_info) = (&g[0], &g[1], &g[2], &g[3], &g[4], &g[5]);
+
+        p.epoch.publish_sequence = p.sequence;
+        p.commit.revealed = true;
+        p.market.last_sequence = p.sequence;
+
+        let (mut audit, audit_bump) = load_or_create::<AuditLog>(&revealer, audit_info, &system_program, AuditLog::LEN, &[AUDIT_SEED, epoch_info.key.as_ref()])?;
+        audit.market = market_info.key();
+        audit.epoch = epoch_info.key();
+        audit.last_bundle_hash = content_hash;
+        audit.last_sequence = p.sequence;
+        audit.last_revealed_at_slot = now;
+        if audit.rent_payer == Pubkey::default() {
+            audit.rent_payer = revealer.key();
+        }
+        audit.bump = audit_bump;
+
+        let (mut prediction, prediction_bump) = load_or_create::<LatestPrediction>(&revealer, prediction_info, &system_program, LatestPrediction::LEN, &[PREDICTION_SEED, market_info.key.as_ref()])?;
+        prediction.apply_reveal(&p.market, mr, bundle.signer_set_id, content_hash, p.sequence, now);
+        prediction.market = market_info.key();
+        prediction.bump = prediction_bump;
+
+        let history = AccountLoader::<RevealHistory>::try_from(history_info)?;
+        {
+            let mut h = history.load_mut()?;
+            if h.market != market_info.key() {
+                return err!(M0OracleError::InvalidBatch);
+            }
+            h.push(RevealHistoryEntry {
+                bundle_hash: content_hash,
+                sequence: p.sequence,
+                signer_set_id: bundle.signer_set_id,
+                epoch_id: p.epoch.epoch_id,
+                slot: now,
+                revealer: revealer.key(),
+            });
+        }
+
+        store(market_info, &p.market)?;
+        store(epoch_info, &p.epoch)?;
+        store(commit_info, &p.commit)?;
+        store(audit_info, &audit)?;
+        store(prediction_info, &prediction)?;
+
+        emit!(PredictionRevealed {
+            market: market_info.key(),
+            epoch: epoch_info.key(),
+            revealer: revealer.key(),
+            bundle_hash: content_hash,
+            sequence: p.sequence,
+        });
+    }
+
+    emit!(BatchRevealed {
+        revealer: revealer.key(),
+        bundle_hash: content_hash,
+        market_count: count as u8,
+        revealed_at_slot: now,
+    });
+
+    Ok(())
+}
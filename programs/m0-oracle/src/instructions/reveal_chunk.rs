@@ -0,0 +1,76 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::M0OracleError;
+use crate::events::*;
+use crate::state::chunked::ChunkedReveal;
+use crate::state::config::ProtocolConfig;
+use crate::utils::hashing::hash_chunk;
+use crate::verify::merkle::verify_merkle_proof;
+
+// Merkle chunk mode, step 2: publish one chunk with its inclusion proof.
+// Chunk bytes are not stored; they live in the transaction and the event carries
+// the chunk hash. Anyone may publish a chunk since the proof binds it to the
+// signed root.
+
+#[derive(Accounts)]
+pub struct RevealChunk<'info> {
+    pub publisher: Signer<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [CHUNKED_REVEAL_SEED, chunked.commit.as_ref()],
+        bump = chunked.bump
+    )]
+    pub chunked: Account<'info, ChunkedReveal>,
+}
+
+pub fn handler(ctx: Context<RevealChunk>, chunk_index: u32, chunk: Vec<u8>, proof: Vec<[u8; 32]>) -> Result<()> {
+    let cfg = &ctx.accounts.config;
+    if cfg.paused {
+        return err!(M0OracleError::Paused);
+    }
+
+    let ch = &mut ctx.accounts.chunked;
+    if chunk_index >= ch.chunk_count || proof.len() > MAX_MERKLE_PROOF_LEN {
+        return err!(M0OracleError::InvalidParameter);
+    }
+    if ch.is_chunk_revealed(chunk_index) {
+        return err!(M0OracleError::ReplayViolation);
+    }
+    if !verify_merkle_proof(chunk_index, &chunk, &ch.merkle_root, &proof) {
+        return err!(M0OracleError::BundleHashMismatch);
+    }
+
+    ch.mark_chunk_revealed(chunk_index);
+
+    let now = Clock::get()?.slot;
+    emit!(ChunkRevealed {
+        chunked: ch.key(),
+        market: ch.market,
+        epoch: ch.epoch,
+        chunk_index,
+        chunk_hash: hash_chunk(chunk_index, &chunk),
+        revealed_at_slot: now,
+    });
+
+    if ch.is_complete() && ch.completed_at_slot == 0 {
+        ch.completed_at_slot = now;
+        emit!(ChunkedRevealCompleted {
+            chunked: ch.key(),
+            market: ch.market,
+            epoch: ch.epoch,
+            merkle_root: ch.merkle_root,
+            chunk_count: ch.chunk_count,
+            sequence: ch.sequence,
+        });
+    }
+
+    Ok(())
+}
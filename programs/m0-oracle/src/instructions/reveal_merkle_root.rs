@@ -0,0 +1,219 @@
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as ix_sysvar;
+use crate::constants::*;
+use crate::error::M0OracleError;
+use crate::events::*;
+use crate::state::audit::AuditLog;
+use crate::state::chunked::ChunkedReveal;
+use crate::state::commit::CommitRecord;
+use crate::state::config::ProtocolConfig;
+use crate::state::epoch::Epoch;
+use crate::state::history::{RevealHistory, RevealHistoryEntry};
+use crate::state::market::Market;
+use crate::state::role::{Role, RoleAssignment};
+use crate::utils::access::require_role;
+use crate::state::signer_set::SignerSet;
+use crate::utils::hashing::{hash_commit, hash_merkle_commitment, hash_signature_message};
+use crate::verify::bundle::require_publish_epoch;
+use crate::verify::signature::verify_threshold_signatures;
+
+// Merkle chunk mode, step 1: open the commitment with the merkle root.
+// In this mode hash_merkle_commitment(root, chunk_count) stands in for the bundle
+// content hash, so the commit preimage and the signer message both bind the chunk
+// count and a reveal cannot complete early by declaring fewer chunks. Chunks are
+// published afterwards with `reveal_chunk`, possibly across many transactions.
+// Chunk content is only proven against the root, never decoded, so this mode is
+// limited to markets with `merkle_reveals` set: they publish no LatestPrediction and
+// consumers cannot read them. The commitment is what audit, history and disputes see.
+
+#[derive(Accounts)]
+pub struct RevealMerkleRoot<'info> {
+    #[account(mut)]
+    pub revealer: Signer<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    // Publisher RoleAssignment of the signer; omit when the signer is the protocol authority.
+    pub role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, market.market_id.as_bytes()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        seeds = [EPOCH_SEED, market.key().as_ref(), &epoch.epoch_id.to_le_bytes()],
+        bump = epoch.bump
+    )]
+    pub epoch: Account<'info, Epoch>,
+
+    #[account(
+        mut,
+        seeds = [COMMIT_SEED, epoch.key().as_ref(), revealer.key().as_ref()],
+        bump = commit.bump
+    )]
+    pub commit: Account<'info, CommitRecord>,
+
+    #[account(
+        seeds = [SIGNER_SET_SEED, &signer_set.signer_set_id.to_le_bytes()],
+        bump = signer_set.bump
+    )]
+    pub signer_set: Account<'info, SignerSet>,
+
+    #[account(
+        init_if_needed,
+        payer = revealer,
+        space = AuditLog::LEN,
+        seeds = [AUDIT_SEED, epoch.key().as_ref()],
+        bump
+    )]
+    pub audit: Account<'info, AuditLog>,
+
+    #[account(
+        mut,
+        seeds = [HISTORY_SEED, market.key().as_ref()],
+        bump = history.load()?.bump
+    )]
+    pub history: AccountLoader<'info, RevealHistory>,
+
+    #[account(
+        init,
+        payer = revealer,
+        space = ChunkedReveal::LEN,
+        seeds = [CHUNKED_REVEAL_SEED, commit.key().as_ref()],
+        bump
+    )]
+    pub chunked: Account<'info, ChunkedReveal>,
+
+    /// CHECK: address is constrained to the instructions sysvar; parsed in verify::signature.
+    #[account(address = ix_sysvar::ID @ M0OracleError::InvalidInstructionsSysvar)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RevealMerkleRoot>, merkle_root: [u8; 32], chunk_count: u32, signer_set_id: u64, publish_epoch_id: u64, salt: [u8; 32]) -> Result<()> {
+    let cfg = &ctx.accounts.config;
+    if cfg.paused {
+        return err!(M0OracleError::Paused);
+    }
+
+    let m = &ctx.accounts.market;
+    require_role(cfg, &ctx.accounts.revealer.key(), ctx.accounts.role.as_deref(), Role::Publisher, Some(&m.key()), Some(&m.domain))?;
+
+    if !ctx.accounts.market.active {
+        return err!(M0OracleError::MarketNotActive);
+    }
+    if !ctx.accounts.epoch.open {
+        return err!(M0OracleError::EpochNotOpen);
+    }
+    if ctx.accounts.market.min_publishers > 0 {
+        return err!(M0OracleError::AggregationRequiresFullReveal);
+    }
+    if !ctx.accounts.market.merkle_reveals {
+        return err!(M0OracleError::RevealModeMismatch);
+    }
+    if chunk_count == 0 || chunk_count > MAX_MERKLE_CHUNKS {
+        return err!(M0OracleError::InvalidParameter);
+    }
+    require_publish_epoch(publish_epoch_id, ctx.accounts.epoch.epoch_id)?;
+
+    let c = &mut ctx.accounts.commit;
+    if c.revealed {
+        return err!(M0OracleError::CommitAlreadyRevealed);
+    }
+
+    let now = Clock::get()?.slot;
+    if now < c.reveal_after_slot {
+        return err!(M0OracleError::RevealTooEarly);
+    }
+    if c.expired || now > c.reveal_deadline_slot {
+        return err!(M0OracleError::RevealDeadlinePassed);
+    }
+
+    // 1) verify commit preimage matches (root + chunk count replace the content hash)
+    let commitment = hash_merkle_commitment(&merkle_root, chunk_count);
+    let expected_commit = hash_commit(&commitment, &salt);
+    if expected_commit != c.commit_hash {
+        return err!(M0OracleError::RevealMismatch);
+    }
+
+    // 2) replay protection: advance epoch sequence monotonically
+    let e = &mut ctx.accounts.epoch;
+    let next_seq = e.publish_sequence.saturating_add(1);
+    e.publish_sequence = next_seq;
+
+    // 3) verify threshold signatures over root + chunk count
+    let ss = &ctx.accounts.signer_set;
+    ss.require_usable(signer_set_id, now)?;
+    SignerSet::validate(ss.threshold, ss.pubkeys.len())?;
+
+    let sig_msg = hash_signature_message(&commitment, &ctx.accounts.market.key(), &e.key(), signer_set_id, publish_epoch_id, next_seq);
+    verify_threshold_signatures(&ctx.accounts.instructions.to_account_info(), &sig_msg, &ss.pubkeys, ss.threshold)?;
+
+    // 4) record root for subsequent chunk reveals
+    let ch = &mut ctx.accounts.chunked;
+    ch.market = ctx.accounts.market.key();
+    ch.epoch = e.key();
+    ch.commit = c.key();
+    ch.merkle_root = merkle_root;
+    ch.signer_set_id = signer_set_id;
+    ch.sequence = next_seq;
+    ch.chunk_count = chunk_count;
+    ch.chunks_revealed = 0;
+    ch.revealed_bitmap = [0u8; 32];
+    ch.root_revealed_at_slot = now;
+    ch.completed_at_slot = 0;
+    ch.bump = *ctx.bumps.get("chunked").unwrap();
+
+    // 5) write audit
+    let audit = &mut ctx.accounts.audit;
+    audit.market = ch.market;
+    audit.epoch = ch.epoch;
+    audit.last_bundle_hash = commitment;
+    audit.last_sequence = next_seq;
+    audit.last_revealed_at_slot = now;
+    if audit.rent_payer == Pubkey::default() {
+        audit.rent_payer = ctx.accounts.revealer.key();
+    }
+    audit.bump = *ctx.bumps.get("audit").unwrap();
+
+    // 6) append to the market's reveal history
+    ctx.accounts.history.load_mut()?.push(RevealHistoryEntry {
+        bundle_hash: commitment,
+        sequence: next_seq,
+        signer_set_id,
+        epoch_id: e.epoch_id,
+        slot: now,
+        revealer: ctx.accounts.revealer.key(),
+    });
+
+    // 7) mark revealed
+    c.revealed = true;
+    c.has_chunked = true;
+
+    // 8) update market last_sequence
+    let m = &mut ctx.accounts.market;
+    m.last_sequence = next_seq;
+
+    emit!(MerkleRootRevealed {
+        market: m.key(),
+        epoch: e.key(),
+        chunked: ch.key(),
+        revealer: ctx.accounts.revealer.key(),
+        merkle_root,
+        chunk_count,
+        bundle_hash: commitment,
+        sequence: next_seq,
+    });
+
+    Ok(())
+}
@@ -1,16 +1,23 @@
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as ix_sysvar;
 use crate::error::M0OracleError;
 use crate::events::*;
 use crate::state::audit::AuditLog;
 use crate::state::commit::CommitRecord;
 use crate::state::config::ProtocolConfig;
 use crate::state::epoch::Epoch;
+use crate::state::history::{RevealHistory, RevealHistoryEntry};
 use crate::state::market::Market;
+use crate::state::role::{Role, RoleAssignment};
+use crate::utils::access::{publisher_weight, require_role};
+use crate::state::prediction::{predicted_outcomes, LatestPrediction};
 use crate::state::reveal::BundleReveal;
 use crate::state::signer_set::SignerSet;
+use crate::state::submission::PublisherSubmission;
 use crate::utils::hashing::{hash_commit, hash_bundle_content, hash_signature_message};
-use crate::verify::signature::verify_threshold_signatures_placeholder;
+use crate::verify::bundle::{decode_and_match, find_market_reveal, require_publish_epoch, validate_market_reveal};
+use crate::verify::signature::verify_threshold_signatures;
 
 #[derive(Accounts)]
 pub struct RevealPrediction<'info> {
@@ -23,7 +30,11 @@ pub struct RevealPrediction<'info> {
     )]
     pub config: Account<'info, ProtocolConfig>,
 
+    // Publisher RoleAssignment of the signer; omit when the signer is the protocol authority.
+    pub role: Option<Account<'info, RoleAssignment>>,
+
     #[account(
+        mut,
         seeds = [crate::constants::MARKET_SEED, market.market_id.as_bytes()],
         bump = market.bump
     )]
@@ -58,6 +69,36 @@ pub struct RevealPrediction<'info> {
     )]
     pub audit: Account<'info, AuditLog>,
 
+    #[account(
+        mut,
+        seeds = [crate::constants::HISTORY_SEED, market.key().as_ref()],
+        bump = history.load()?.bump
+    )]
+    pub history: AccountLoader<'info, RevealHistory>,
+
+    #[account(
+        init_if_needed,
+        payer = revealer,
+        space = LatestPrediction::LEN,
+        seeds = [crate::constants::PREDICTION_SEED, market.key().as_ref()],
+        bump
+    )]
+    pub prediction: Account<'info, LatestPrediction>,
+
+    // Required when market.min_publishers > 0; combined at finalize_epoch.
+    #[account(
+        init,
+        payer = revealer,
+        space = PublisherSubmission::LEN,
+        seeds = [crate::constants::SUBMISSION_SEED, epoch.key().as_ref(), revealer.key().as_ref()],
+        bump
+    )]
+    pub submission: Option<Account<'info, PublisherSubmission>>,
+
+    /// CHECK: address is constrained to the instructions sysvar; parsed in verify::signature.
+    #[account(address = ix_sysvar::ID @ M0OracleError::InvalidInstructionsSysvar)]
+    pub instructions: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -67,9 +108,15 @@ pub fn handler(ctx: Context<RevealPrediction>, bundle: BundleReveal, salt: [u8;
         return err!(M0OracleError::Paused);
     }
 
+    let m = &ctx.accounts.market;
+    require_role(cfg, &ctx.accounts.revealer.key(), ctx.accounts.role.as_deref(), Role::Publisher, Some(&m.key()), Some(&m.domain))?;
+
     if !ctx.accounts.market.active {
         return err!(M0OracleError::MarketNotActive);
     }
+    if ctx.accounts.market.merkle_reveals {
+        return err!(M0OracleError::RevealModeMismatch);
+    }
     if !ctx.accounts.epoch.open {
         return err!(M0OracleError::EpochNotOpen);
     }
@@ -83,6 +130,9 @@ pub fn handler(ctx: Context<RevealPrediction>, bundle: BundleReveal, salt: [u8;
     if now < c.reveal_after_slot {
         return err!(M0OracleError::RevealTooEarly);
     }
+    if c.expired || now > c.reveal_deadline_slot {
+        return err!(M0OracleError::RevealDeadlinePassed);
+    }
 
     // 1) compute content hash
     let content_hash = hash_bundle_content(&bundle_bytes);
@@ -93,34 +143,75 @@ pub fn handler(ctx: Context<RevealPrediction>, bundle: BundleReveal, salt: [u8;
         return err!(M0OracleError::RevealMismatch);
     }
 
-    // 3) replay protection: advance epoch sequence monotonically
+    // 3) decode the canonical bundle and validate this market's entry
+    decode_and_match(&bundle_bytes, &bundle)?;
+    require_publish_epoch(bundle.publish_epoch_id, ctx.accounts.epoch.epoch_id)?;
+    let mr = find_market_reveal(&bundle, &ctx.accounts.market.market_id)?;
+    validate_market_reveal(&ctx.accounts.market, ctx.accounts.epoch.epoch_id, mr)?;
+
+    // 4) replay protection: advance epoch sequence monotonically
     let e = &mut ctx.accounts.epoch;
     let next_seq = e.publish_sequence.saturating_add(1);
     e.publish_sequence = next_seq;
 
-    // 4) verify signer set (placeholder in this skeleton)
+    // 5) verify threshold signatures from the signer set (Ed25519Program ixs in this tx)
     let ss = &ctx.accounts.signer_set;
-    if !ss.active {
-        return err!(M0OracleError::SignerSetNotActive);
-    }
+    ss.require_usable(bundle.signer_set_id, now)?;
     SignerSet::validate(ss.threshold, ss.pubkeys.len())?;
 
-    let sig_msg = hash_signature_message(&content_hash, bundle.signer_set_id, bundle.publish_epoch_id, next_seq);
-    verify_threshold_signatures_placeholder(&sig_msg, &ss.pubkeys, ss.threshold)?;
+    let sig_msg = hash_signature_message(&content_hash, &ctx.accounts.market.key(), &e.key(), bundle.signer_set_id, bundle.publish_epoch_id, next_seq);
+    verify_threshold_signatures(&ctx.accounts.instructions.to_account_info(), &sig_msg, &ss.pubkeys, ss.threshold)?;
 
-    // 5) write audit
+    // 6) write audit
     let audit = &mut ctx.accounts.audit;
     audit.market = ctx.accounts.market.key();
-    audit.epoch = ctx.accounts.epoch.key();
+    audit.epoch = e.key();
     audit.last_bundle_hash = content_hash;
     audit.last_sequence = next_seq;
     audit.last_revealed_at_slot = now;
+    if audit.rent_payer == Pubkey::default() {
+        audit.rent_payer = ctx.accounts.revealer.key();
+    }
     audit.bump = *ctx.bumps.get("audit").unwrap();
 
-    // 6) mark revealed
+    // 7) publish consumer-readable output, or record a submission in aggregation mode
+    if ctx.accounts.market.min_publishers > 0 {
+        let s = ctx.accounts.submission.as_mut().ok_or(M0OracleError::InvalidSubmission)?;
+        s.epoch = e.key();
+        s.publisher = ctx.accounts.revealer.key();
+        s.weight = publisher_weight(&s.publisher, ctx.accounts.role.as_deref());
+        s.signer_set_id = bundle.signer_set_id;
+        s.bundle_hash = content_hash;
+        s.sequence = next_seq;
+        s.observed_at_ms = mr.observed_at_ms;
+        s.risk_score = mr.risk_score;
+        s.quality_flags = mr.quality_flags;
+        s.revealed_at_slot = now;
+        s.outcomes = predicted_outcomes(&ctx.accounts.market, mr);
+        s.bump = *ctx.bumps.get("submission").unwrap();
+        e.submission_count = e.submission_count.saturating_add(1);
+    } else {
+        ctx.accounts.prediction.apply_reveal(&ctx.accounts.market, mr, bundle.signer_set_id, content_hash, next_seq, now);
+    }
+    let p = &mut ctx.accounts.prediction;
+    p.market = ctx.accounts.market.key();
+    p.bump = *ctx.bumps.get("prediction").unwrap();
+
+    // 8) append to the market's reveal history
+    ctx.accounts.history.load_mut()?.push(RevealHistoryEntry {
+        bundle_hash: content_hash,
+        sequence: next_seq,
+        signer_set_id: bundle.signer_set_id,
+        epoch_id: e.epoch_id,
+        slot: now,
+        revealer: ctx.accounts.revealer.key(),
+    });
+
+    // 9) mark revealed
     c.revealed = true;
+    c.has_submission = ctx.accounts.submission.is_some();
 
-    // 7) update market last_sequence
+    // 10) update market last_sequence
     let m = &mut ctx.accounts.market;
     m.last_sequence = next_seq;
 
@@ -0,0 +1,54 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::M0OracleError;
+use crate::events::*;
+use crate::state::config::ProtocolConfig;
+use crate::state::role::{Role, RoleAssignment, RoleScope};
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(mut)]
+    pub revoker: Signer<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    // Admin assignment of the revoker; omit when the revoker is the protocol authority.
+    pub revoker_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        mut,
+        close = revoker,
+        seeds = [ROLE_SEED, &[assignment.role as u8], assignment.grantee.as_ref(), &assignment.scope.seed_tag(), &assignment.scope.seed_payload()],
+        bump = assignment.bump
+    )]
+    pub assignment: Account<'info, RoleAssignment>,
+}
+
+pub fn handler(ctx: Context<RevokeRole>) -> Result<()> {
+    let cfg = &ctx.accounts.config;
+    let revoker = ctx.accounts.revoker.key();
+    let a = &ctx.accounts.assignment;
+
+    if revoker != cfg.authority {
+        let admin = ctx.accounts.revoker_role.as_deref().ok_or(M0OracleError::Unauthorized)?;
+        if admin.grantee != revoker || admin.role != Role::Admin || admin.scope != RoleScope::Global || a.role == Role::Admin {
+            return err!(M0OracleError::Unauthorized);
+        }
+    }
+
+    emit!(RoleRevoked {
+        assignment: a.key(),
+        role: a.role,
+        grantee: a.grantee,
+        scope: a.scope,
+        revoked_by: revoker,
+        revoked_at_slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
@@ -3,10 +3,15 @@ use anchor_lang::prelude::*;
 use crate::error::M0OracleError;
 use crate::events::*;
 use crate::state::config::ProtocolConfig;
-use crate::state::signer_set::SignerSet;
+use crate::state::signer_set::{SignerSet, SignerSetPurpose};
 use crate::constants::*;
 
+// Schedules a new signer set. The current set stays valid until
+// `activation_slot + overlap_slots`, so both sets can sign during the overlap window.
+// `activate_signer_set` records the new set as current once the activation slot is reached.
+
 #[derive(Accounts)]
+#[instruction(threshold: u16, pubkeys: Vec<Pubkey>)]
 pub struct RotateSignerSet<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -19,6 +24,14 @@ pub struct RotateSignerSet<'info> {
     )]
     pub config: Account<'info, ProtocolConfig>,
 
+    // Currently active set; required once a set has been activated.
+    #[account(
+        mut,
+        seeds = [SIGNER_SET_SEED, &current.signer_set_id.to_le_bytes()],
+        bump = current.bump
+    )]
+    pub current: Option<Account<'info, SignerSet>>,
+
     #[account(
         init,
         payer = authority,
@@ -31,30 +44,61 @@ pub struct RotateSignerSet<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<RotateSignerSet>, threshold: u16, pubkeys: Vec<Pubkey>, active: bool) -> Result<()> {
+pub fn handler(ctx: Context<RotateSignerSet>, threshold: u16, pubkeys: Vec<Pubkey>, activation_slot: Option<u64>, overlap_slots: u64) -> Result<()> {
     let cfg = &mut ctx.accounts.config;
     if cfg.paused {
         return err!(M0OracleError::Paused);
     }
+    if cfg.pending_signer_set_id != 0 {
+        return err!(M0OracleError::SignerSetRotationPending);
+    }
+    if overlap_slots > cfg.max_signer_overlap_slots {
+        return err!(M0OracleError::SignerOverlapTooLong);
+    }
 
     SignerSet::validate(threshold, pubkeys.len())?;
 
+    let now = Clock::get()?.slot;
+    let activation = activation_slot.unwrap_or(now);
+    if activation < now {
+        return err!(M0OracleError::InvalidParameter);
+    }
+
+    // bound the current set's lifetime to the overlap window
+    let (prev_id, prev_expires) = if cfg.active_signer_set_id != 0 {
+        let cur = ctx.accounts.current.as_mut().ok_or(M0OracleError::InvalidParameter)?;
+        if cur.signer_set_id != cfg.active_signer_set_id {
+            return err!(M0OracleError::SignerSetMismatch);
+        }
+        cur.expires_at_slot = activation.saturating_add(overlap_slots);
+        (cur.signer_set_id, cur.expires_at_slot)
+    } else {
+        (0, 0)
+    };
+
     let ss_id = cfg.next_signer_set_id;
     cfg.next_signer_set_id = cfg.next_signer_set_id.saturating_add(1);
+    cfg.pending_signer_set_id = ss_id;
 
     let ss = &mut ctx.accounts.signer_set;
     ss.signer_set_id = ss_id;
     ss.threshold = threshold;
     ss.pubkeys = pubkeys;
-    ss.active = active;
-    ss.created_at_slot = Clock::get()?.slot;
+    ss.active = true;
+    ss.created_at_slot = now;
+    ss.activation_slot = activation;
+    ss.expires_at_slot = 0;
+    ss.deactivated_at_slot = 0;
+    ss.purpose = SignerSetPurpose::Publish;
     ss.bump = *ctx.bumps.get("signer_set").unwrap();
 
     emit!(SignerSetRotated {
         signer_set: ss.key(),
         signer_set_id: ss.signer_set_id,
         threshold: ss.threshold,
-        active: ss.active,
+        activation_slot: activation,
+        previous_signer_set_id: prev_id,
+        previous_expires_at_slot: prev_expires,
     });
 
     Ok(())
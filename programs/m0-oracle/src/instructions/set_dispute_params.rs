@@ -0,0 +1,45 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::M0OracleError;
+use crate::events::*;
+use crate::state::config::ProtocolConfig;
+use crate::state::role::{Role, RoleAssignment};
+use crate::utils::access::require_role;
+
+#[derive(Accounts)]
+pub struct SetDisputeParams<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROTOCOL_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    // Admin RoleAssignment of the signer; omit when the signer is the protocol authority.
+    pub role: Option<Account<'info, RoleAssignment>>,
+}
+
+pub fn handler(ctx: Context<SetDisputeParams>, dispute_window_slots: Option<u64>, min_dispute_bond_lamports: Option<u64>) -> Result<()> {
+    require_role(&ctx.accounts.config, &ctx.accounts.authority.key(), ctx.accounts.role.as_deref(), Role::Admin, None, None)?;
+
+    let cfg = &mut ctx.accounts.config;
+    if let Some(w) = dispute_window_slots {
+        cfg.dispute_window_slots = w;
+    }
+    if let Some(b) = min_dispute_bond_lamports {
+        if b == 0 {
+            return err!(M0OracleError::InvalidParameter);
+        }
+        cfg.min_dispute_bond_lamports = b;
+    }
+
+    emit!(DisputeParamsSet {
+        dispute_window_slots: cfg.dispute_window_slots,
+        min_dispute_bond_lamports: cfg.min_dispute_bond_lamports,
+    });
+
+    Ok(())
+}
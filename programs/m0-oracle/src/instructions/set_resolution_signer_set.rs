@@ -0,0 +1,69 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::M0OracleError;
+use crate::events::*;
+use crate::state::config::ProtocolConfig;
+use crate::state::signer_set::{SignerSet, SignerSetPurpose};
+
+// Creates a new resolution signer set and points resolve_market at it immediately.
+// The previous set is no longer accepted; deactivate it with deactivate_signer_set.
+// Resolution sets share the signer set id space but can never sign bundles.
+
+#[derive(Accounts)]
+#[instruction(threshold: u16, pubkeys: Vec<Pubkey>)]
+pub struct SetResolutionSignerSet<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROTOCOL_SEED],
+        bump = config.bump,
+        has_one = authority @ M0OracleError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = SignerSet::len_with(pubkeys.len()),
+        seeds = [SIGNER_SET_SEED, &config.next_signer_set_id.to_le_bytes()],
+        bump
+    )]
+    pub signer_set: Account<'info, SignerSet>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetResolutionSignerSet>, threshold: u16, pubkeys: Vec<Pubkey>) -> Result<()> {
+    SignerSet::validate(threshold, pubkeys.len())?;
+
+    let cfg = &mut ctx.accounts.config;
+    let previous = cfg.resolution_signer_set_id;
+    let ss_id = cfg.next_signer_set_id;
+    cfg.next_signer_set_id = cfg.next_signer_set_id.saturating_add(1);
+    cfg.resolution_signer_set_id = ss_id;
+
+    let now = Clock::get()?.slot;
+    let ss = &mut ctx.accounts.signer_set;
+    ss.signer_set_id = ss_id;
+    ss.threshold = threshold;
+    ss.pubkeys = pubkeys;
+    ss.active = true;
+    ss.created_at_slot = now;
+    ss.activation_slot = now;
+    ss.expires_at_slot = 0;
+    ss.deactivated_at_slot = 0;
+    ss.purpose = SignerSetPurpose::Resolution;
+    ss.bump = *ctx.bumps.get("signer_set").unwrap();
+
+    emit!(ResolutionSignerSetSet {
+        signer_set: ss.key(),
+        signer_set_id: ss_id,
+        threshold,
+        previous_signer_set_id: previous,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::M0OracleError;
+use crate::events::*;
+use crate::state::config::ProtocolConfig;
+use crate::state::dispute::{CorrectionAction, DisputeRecord, DisputeState};
+use crate::state::epoch::{Epoch, OutputStatus};
+use crate::state::market::Market;
+use crate::state::prediction::LatestPrediction;
+use crate::state::role::{Role, RoleAssignment};
+use crate::utils::access::require_role;
+
+// Correction backed by an accepted dispute. With a corrective bundle hash the output is
+// Superseded and points at the replacement; without one it is Retracted.
+// The old bundle stays auditable through the reveal history and the dispute record.
+
+#[derive(Accounts)]
+pub struct SupersedeEpoch<'info> {
+    pub resolver: Signer<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    // DisputeResolver RoleAssignment of the signer; omit when the signer is the protocol authority.
+    pub role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        seeds = [MARKET_SEED, market.market_id.as_bytes()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        seeds = [EPOCH_SEED, market.key().as_ref(), &epoch.epoch_id.to_le_bytes()],
+        bump = epoch.bump
+    )]
+    pub epoch: Account<'info, Epoch>,
+
+    #[account(
+        mut,
+        seeds = [DISPUTE_SEED, epoch.key().as_ref(), dispute.bundle_hash.as_ref()],
+        bump = dispute.bump
+    )]
+    pub dispute: Account<'info, DisputeRecord>,
+
+    #[account(
+        mut,
+        seeds = [PREDICTION_SEED, market.key().as_ref()],
+        bump = prediction.bump
+    )]
+    pub prediction: Option<Account<'info, LatestPrediction>>,
+}
+
+pub fn handler(ctx: Context<SupersedeEpoch>, corrective_bundle_hash: Option<[u8; 32]>) -> Result<()> {
+    let m = &ctx.accounts.market;
+    require_role(&ctx.accounts.config, &ctx.accounts.resolver.key(), ctx.accounts.role.as_deref(), Role::DisputeResolver, Some(&m.key()), Some(&m.domain))?;
+
+    let d = &mut ctx.accounts.dispute;
+    if d.state != DisputeState::Accepted || d.action != CorrectionAction::None {
+        return err!(M0OracleError::DisputeNotAccepted);
+    }
+
+    let e = &mut ctx.accounts.epoch;
+    if matches!(e.output_status, OutputStatus::Retracted | OutputStatus::Superseded) {
+        return err!(M0OracleError::OutputAlreadyCorrected);
+    }
+
+    let (status, action, pointer) = match corrective_bundle_hash {
+        Some(h) => (OutputStatus::Superseded, CorrectionAction::Supersede, h),
+        None => (OutputStatus::Retracted, CorrectionAction::Retract, [0u8; 32]),
+    };
+
+    d.action = action;
+    d.corrective_bundle_hash = pointer;
+    e.output_status = status;
+    e.superseded_by = pointer;
+
+    match ctx.accounts.prediction.as_mut() {
+        Some(p) => p.mark_status(e.epoch_id, status, pointer),
+        None if e.publish_sequence == 0 => {}
+        None => return err!(M0OracleError::InvalidParameter),
+    }
+
+    let now = Clock::get()?.slot;
+    match action {
+        CorrectionAction::Supersede => emit!(OutputSuperseded {
+            market: m.key(),
+            epoch: e.key(),
+            epoch_id: e.epoch_id,
+            target_bundle_hash: d.bundle_hash,
+            corrective_bundle_hash: pointer,
+            dispute: d.key(),
+            evidence_hash: d.evidence_hash,
+            resolver: ctx.accounts.resolver.key(),
+            applied_at_slot: now,
+        }),
+        _ => emit!(OutputRetracted {
+            market: m.key(),
+            epoch: e.key(),
+            epoch_id: e.epoch_id,
+            target_bundle_hash: d.bundle_hash,
+            dispute: d.key(),
+            evidence_hash: d.evidence_hash,
+            resolver: ctx.accounts.resolver.key(),
+            applied_at_slot: now,
+        }),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::events::*;
+use crate::error::M0OracleError;
+use crate::state::config::ProtocolConfig;
+use crate::state::market::{Domain, Market};
+use m0_registry::state::metadata::{MarketMetadata, MARKET_META_SEED};
+
+// Permissionless: pulls the registry entry into the oracle market.
+// - Deactivation propagates; activation does not (update_market re-activates).
+// - Cadence and tier policy are copied; the cadence must still divide epoch_window_ms.
+// - Outcome changes require the market to be inactive, since LatestPrediction and
+//   open epochs index into Market.outcomes.
+// - The domain of a market id never changes.
+// - No resize: create_market allocates for MAX_OUTCOMES outcomes of MAX_OUTCOME_ID_LEN,
+//   which bounds any outcome list validate_ids accepts.
+
+#[derive(Accounts)]
+pub struct SyncMarket<'info> {
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, market.market_id.as_bytes()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        seeds = [MARKET_META_SEED, market.market_id.as_bytes()],
+        bump = registry_meta.bump,
+        seeds::program = m0_registry::ID
+    )]
+    pub registry_meta: Account<'info, MarketMetadata>,
+}
+
+pub fn handler(ctx: Context<SyncMarket>) -> Result<()> {
+    if ctx.accounts.config.paused {
+        return err!(M0OracleError::Paused);
+    }
+
+    let meta = &ctx.accounts.registry_meta;
+    let m = &mut ctx.accounts.market;
+
+    if Domain::from_registry(&meta.domain) != m.domain {
+        return err!(M0OracleError::RegistryMismatch);
+    }
+    if meta.tier_policy.len() > MAX_TIER_POLICY_LEN {
+        return err!(M0OracleError::InvalidParameter);
+    }
+    Market::validate_cadence(m.epoch_window_ms, meta.cadence_ms as u64)?;
+
+    let outcomes_changed = meta.outcomes != m.outcomes;
+    if outcomes_changed {
+        if m.active {
+            return err!(M0OracleError::MarketMustBeInactive);
+        }
+        Market::validate_ids(&m.market_id, &meta.outcomes)?;
+        m.outcomes = meta.outcomes.clone();
+    }
+
+    m.active = m.active && meta.is_active();
+    m.publish_cadence_ms = meta.cadence_ms as u64;
+    m.tier_policy = meta.tier_policy.clone();
+    m.registry_updated_at_slot = meta.updated_at_slot;
+
+    emit!(MarketSynced {
+        market: m.key(),
+        market_id: m.market_id.clone(),
+        active: m.active,
+        publish_cadence_ms: m.publish_cadence_ms,
+        tier_policy: m.tier_policy.clone(),
+        outcomes_changed,
+        registry_updated_at_slot: m.registry_updated_at_slot,
+        synced_at_slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
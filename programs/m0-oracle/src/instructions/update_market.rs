@@ -5,6 +5,9 @@ use crate::events::*;
 use crate::error::M0OracleError;
 use crate::state::config::ProtocolConfig;
 use crate::state::market::Market;
+use crate::state::role::{Role, RoleAssignment};
+use crate::utils::access::require_role;
+use m0_registry::state::metadata::{MarketMetadata, MARKET_META_SEED};
 
 #[derive(Accounts)]
 pub struct UpdateMarket<'info> {
@@ -13,34 +16,74 @@ pub struct UpdateMarket<'info> {
 
     #[account(
         seeds = [PROTOCOL_SEED],
-        bump = config.bump,
-        has_one = authority @ M0OracleError::Unauthorized
+        bump = config.bump
     )]
     pub config: Account<'info, ProtocolConfig>,
 
+    // RoleAssignment of the signer; omit when the signer is the protocol authority.
+    pub role: Option<Account<'info, RoleAssignment>>,
+
     #[account(
         mut,
         seeds = [MARKET_SEED, market.market_id.as_bytes()],
         bump = market.bump
     )]
     pub market: Account<'info, Market>,
+
+    // Updates are refused while the market has drifted from the registry; run sync_market.
+    #[account(
+        seeds = [MARKET_META_SEED, market.market_id.as_bytes()],
+        bump = registry_meta.bump,
+        seeds::program = m0_registry::ID
+    )]
+    pub registry_meta: Account<'info, MarketMetadata>,
 }
 
-pub fn handler(ctx: Context<UpdateMarket>, active: Option<bool>) -> Result<()> {
+pub fn handler(ctx: Context<UpdateMarket>, active: Option<bool>, min_publishers: Option<u8>, publish_fee_lamports: Option<u64>, merkle_reveals: Option<bool>) -> Result<()> {
     let cfg = &ctx.accounts.config;
     if cfg.paused {
         return err!(M0OracleError::Paused);
     }
 
     let m = &mut ctx.accounts.market;
+    require_role(cfg, &ctx.accounts.authority.key(), ctx.accounts.role.as_deref(), Role::MarketOperator, Some(&m.key()), Some(&m.domain))?;
+
+    let meta = &ctx.accounts.registry_meta;
+    Market::validate_registry(meta, m.domain, &m.outcomes, m.publish_cadence_ms, &m.tier_policy)?;
+
     if let Some(a) = active {
+        if a && m.resolved {
+            return err!(M0OracleError::MarketResolved);
+        }
+        if a && !meta.is_active() {
+            return err!(M0OracleError::RegistryMarketInactive);
+        }
         m.active = a;
     }
+    if let Some(n) = min_publishers {
+        if n as usize > MAX_AGGREGATE_PUBLISHERS {
+            return err!(M0OracleError::InvalidParameter);
+        }
+        m.min_publishers = n;
+    }
+    if let Some(f) = publish_fee_lamports {
+        m.publish_fee_lamports = f;
+    }
+    if let Some(mr) = merkle_reveals {
+        m.merkle_reveals = mr;
+    }
+    // Merkle chunk reveals carry no per-market content to aggregate.
+    if m.merkle_reveals && m.min_publishers > 0 {
+        return err!(M0OracleError::AggregationRequiresFullReveal);
+    }
 
     emit!(MarketUpdated {
         market: m.key(),
         market_id: m.market_id.clone(),
         active: m.active,
+        min_publishers: m.min_publishers,
+        publish_fee_lamports: m.publish_fee_lamports,
+        merkle_reveals: m.merkle_reveals,
         updated_at_slot: Clock::get()?.slot,
     });
 
@@ -17,43 +17,132 @@ declare_id!("M0Orac1e111111111111111111111111111111111");
 pub mod m0_oracle {
     use super::*;
 
-    pub fn init_protocol(ctx: Context<init_protocol::InitProtocol>, default_reveal_delay_slots: Option<u64>) -> Result<()> {
-        init_protocol::handler(ctx, default_reveal_delay_slots)
+    pub fn init_protocol(ctx: Context<init_protocol::InitProtocol>, default_reveal_delay_slots: Option<u64>, max_reveal_delay_slots: Option<u64>, max_signer_overlap_slots: Option<u64>, finalization_delay_ms: Option<u64>) -> Result<()> {
+        init_protocol::handler(ctx, default_reveal_delay_slots, max_reveal_delay_slots, max_signer_overlap_slots, finalization_delay_ms)
     }
 
-    pub fn create_market(ctx: Context<create_market::CreateMarket>, market_id: String, domain: state::market::Domain, outcomes: Vec<String>, active: bool) -> Result<()> {
-        create_market::handler(ctx, market_id, domain, outcomes, active)
+    pub fn init_reveal_history(ctx: Context<init_reveal_history::InitRevealHistory>) -> Result<()> {
+        init_reveal_history::handler(ctx)
     }
 
-    pub fn update_market(ctx: Context<update_market::UpdateMarket>, active: Option<bool>) -> Result<()> {
-        update_market::handler(ctx, active)
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_market(ctx: Context<create_market::CreateMarket>, market_id: String, domain: state::market::Domain, outcomes: Vec<String>, active: bool, epoch_window_ms: u64, publish_cadence_ms: u64, tier_policy: String) -> Result<()> {
+        create_market::handler(ctx, market_id, domain, outcomes, active, epoch_window_ms, publish_cadence_ms, tier_policy)
     }
 
-    pub fn open_epoch(ctx: Context<open_epoch::OpenEpoch>) -> Result<()> {
-        open_epoch::handler(ctx)
+    pub fn update_market(ctx: Context<update_market::UpdateMarket>, active: Option<bool>, min_publishers: Option<u8>, publish_fee_lamports: Option<u64>, merkle_reveals: Option<bool>) -> Result<()> {
+        update_market::handler(ctx, active, min_publishers, publish_fee_lamports, merkle_reveals)
+    }
+
+    pub fn sync_market(ctx: Context<sync_market::SyncMarket>) -> Result<()> {
+        sync_market::handler(ctx)
+    }
+
+    pub fn open_epoch(ctx: Context<open_epoch::OpenEpoch>, epoch_id: u64) -> Result<()> {
+        open_epoch::handler(ctx, epoch_id)
     }
 
     pub fn commit_prediction(ctx: Context<commit_prediction::CommitPrediction>, commit_hash: [u8; 32], reveal_delay_slots: Option<u64>) -> Result<()> {
         commit_prediction::handler(ctx, commit_hash, reveal_delay_slots)
     }
 
+    pub fn commit_batch<'info>(ctx: Context<'_, '_, '_, 'info, commit_batch::CommitBatch<'info>>, commit_hash: [u8; 32], reveal_delay_slots: Option<u64>) -> Result<()> {
+        commit_batch::handler(ctx, commit_hash, reveal_delay_slots)
+    }
+
     pub fn reveal_prediction(ctx: Context<reveal_prediction::RevealPrediction>, bundle: state::reveal::BundleReveal, salt: [u8; 32], bundle_bytes: Vec<u8>) -> Result<()> {
         reveal_prediction::handler(ctx, bundle, salt, bundle_bytes)
     }
 
+    pub fn reveal_batch<'info>(ctx: Context<'_, '_, 'info, 'info, reveal_batch::RevealBatch<'info>>, salt: [u8; 32], bundle_bytes: Vec<u8>) -> Result<()> {
+        reveal_batch::handler(ctx, salt, bundle_bytes)
+    }
+
+    pub fn reveal_merkle_root(ctx: Context<reveal_merkle_root::RevealMerkleRoot>, merkle_root: [u8; 32], chunk_count: u32, signer_set_id: u64, publish_epoch_id: u64, salt: [u8; 32]) -> Result<()> {
+        reveal_merkle_root::handler(ctx, merkle_root, chunk_count, signer_set_id, publish_epoch_id, salt)
+    }
+
+    pub fn reveal_chunk(ctx: Context<reveal_chunk::RevealChunk>, chunk_index: u32, chunk: Vec<u8>, proof: Vec<[u8; 32]>) -> Result<()> {
+        reveal_chunk::handler(ctx, chunk_index, chunk, proof)
+    }
+
     pub fn finalize_epoch(ctx: Context<finalize_epoch::FinalizeEpoch>) -> Result<()> {
         finalize_epoch::handler(ctx)
     }
 
-    pub fn rotate_signer_set(ctx: Context<rotate_signer_set::RotateSignerSet>, threshold: u16, pubkeys: Vec<Pubkey>, active: bool) -> Result<()> {
-        rotate_signer_set::handler(ctx, threshold, pubkeys, active)
+    pub fn expire_commit(ctx: Context<expire_commit::ExpireCommit>) -> Result<()> {
+        expire_commit::handler(ctx)
+    }
+
+    pub fn close_commit(ctx: Context<close_commit::CloseCommit>) -> Result<()> {
+        close_commit::handler(ctx)
+    }
+
+    pub fn close_epoch(ctx: Context<close_epoch::CloseEpoch>) -> Result<()> {
+        close_epoch::handler(ctx)
+    }
+
+    pub fn open_dispute(ctx: Context<open_dispute::OpenDispute>, reason: state::dispute::DisputeReason, bundle_hash: [u8; 32], evidence_hash: [u8; 32], bond_lamports: u64) -> Result<()> {
+        open_dispute::handler(ctx, reason, bundle_hash, evidence_hash, bond_lamports)
+    }
+
+    pub fn resolve_dispute(ctx: Context<resolve_dispute::ResolveDispute>, accept: bool) -> Result<()> {
+        resolve_dispute::handler(ctx, accept)
+    }
+
+    pub fn close_dispute(ctx: Context<close_dispute::CloseDispute>) -> Result<()> {
+        close_dispute::handler(ctx)
+    }
+
+    pub fn supersede_epoch(ctx: Context<supersede_epoch::SupersedeEpoch>, corrective_bundle_hash: Option<[u8; 32]>) -> Result<()> {
+        supersede_epoch::handler(ctx, corrective_bundle_hash)
+    }
+
+    pub fn set_dispute_params(ctx: Context<set_dispute_params::SetDisputeParams>, dispute_window_slots: Option<u64>, min_dispute_bond_lamports: Option<u64>) -> Result<()> {
+        set_dispute_params::handler(ctx, dispute_window_slots, min_dispute_bond_lamports)
+    }
+
+    pub fn resolve_market(ctx: Context<resolve_market::ResolveMarket>, outcome: state::resolution::ResolutionOutcome, resolution_source_hash: [u8; 32]) -> Result<()> {
+        resolve_market::handler(ctx, outcome, resolution_source_hash)
+    }
+
+    pub fn set_resolution_signer_set(ctx: Context<set_resolution_signer_set::SetResolutionSignerSet>, threshold: u16, pubkeys: Vec<Pubkey>) -> Result<()> {
+        set_resolution_signer_set::handler(ctx, threshold, pubkeys)
+    }
+
+    pub fn rotate_signer_set(ctx: Context<rotate_signer_set::RotateSignerSet>, threshold: u16, pubkeys: Vec<Pubkey>, activation_slot: Option<u64>, overlap_slots: u64) -> Result<()> {
+        rotate_signer_set::handler(ctx, threshold, pubkeys, activation_slot, overlap_slots)
+    }
+
+    pub fn activate_signer_set(ctx: Context<activate_signer_set::ActivateSignerSet>) -> Result<()> {
+        activate_signer_set::handler(ctx)
+    }
+
+    pub fn deactivate_signer_set(ctx: Context<deactivate_signer_set::DeactivateSignerSet>) -> Result<()> {
+        deactivate_signer_set::handler(ctx)
     }
 
     pub fn set_paused(ctx: Context<pause_resume::SetPaused>, paused: bool) -> Result<()> {
         pause_resume::handler(ctx, paused)
     }
 
-    pub fn upgrade_admin(ctx: Context<upgrade_admin::UpgradeAdmin>, new_authority: Pubkey) -> Result<()> {
-        upgrade_admin::handler(ctx, new_authority)
+    pub fn grant_role(ctx: Context<grant_role::GrantRole>, role: state::role::Role, grantee: Pubkey, scope: state::role::RoleScope, weight: Option<u16>) -> Result<()> {
+        grant_role::handler(ctx, role, grantee, scope, weight)
+    }
+
+    pub fn revoke_role(ctx: Context<revoke_role::RevokeRole>) -> Result<()> {
+        revoke_role::handler(ctx)
+    }
+
+    pub fn propose_authority(ctx: Context<propose_authority::ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        propose_authority::handler(ctx, new_authority)
+    }
+
+    pub fn accept_authority(ctx: Context<accept_authority::AcceptAuthority>) -> Result<()> {
+        accept_authority::handler(ctx)
+    }
+
+    pub fn cancel_authority_transfer(ctx: Context<cancel_authority_transfer::CancelAuthorityTransfer>) -> Result<()> {
+        cancel_authority_transfer::handler(ctx)
     }
 }
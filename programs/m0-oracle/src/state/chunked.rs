@@ -0,0 +1,38 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+#[account]
+pub struct ChunkedReveal {
+    pub market: Pubkey,
+    pub epoch: Pubkey,
+    pub commit: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub signer_set_id: u64,
+    pub sequence: u64,
+    pub chunk_count: u32,
+    pub chunks_revealed: u32,
+    pub revealed_bitmap: [u8; 32], // one bit per chunk, MAX_MERKLE_CHUNKS bits
+    pub root_revealed_at_slot: u64,
+    pub completed_at_slot: u64,
+    pub bump: u8,
+}
+
+impl ChunkedReveal {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 32 + 8 + 8 + 4 + 4 + (MAX_MERKLE_CHUNKS as usize / 8) + 8 + 8 + 1;
+
+    pub fn is_chunk_revealed(&self, chunk_index: u32) -> bool {
+        let i = chunk_index as usize;
+        self.revealed_bitmap[i / 8] & (1u8 << (i % 8)) != 0
+    }
+
+    pub fn mark_chunk_revealed(&mut self, chunk_index: u32) {
+        let i = chunk_index as usize;
+        self.revealed_bitmap[i / 8] |= 1u8 << (i % 8);
+        self.chunks_revealed = self.chunks_revealed.saturating_add(1);
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.chunks_revealed >= self.chunk_count
+    }
+}
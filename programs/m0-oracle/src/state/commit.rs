@@ -8,10 +8,18 @@ pub struct CommitRecord {
     pub committer: Pubkey,
     pub commit_hash: [u8; 32],
     pub reveal_after_slot: u64,
+    pub reveal_deadline_slot: u64,
     pub revealed: bool,
+    pub expired: bool, // missed reveal, set by expire_commit
+    pub has_chunked: bool,    // reveal_merkle_root created a ChunkedReveal; closed with the commit
+    pub has_submission: bool, // reveal_prediction created a PublisherSubmission; closed with the commit
     pub bump: u8,
 }
 
 impl CommitRecord {
-    pub const LEN: usize = 8 + 32 + 32 + 32 + 32 + 8 + 1 + 1;
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 32 + 8 + 8 + 1 + 1 + 1 + 1 + 1;
+
+    pub fn is_settled(&self) -> bool {
+        self.revealed || self.expired
+    }
 }
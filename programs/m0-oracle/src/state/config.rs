@@ -6,15 +6,24 @@ use crate::constants::*;
 pub struct ProtocolConfig {
     pub initialized: bool,
     pub authority: Pubkey,
+    pub pending_authority: Option<Pubkey>,
     pub paused: bool,
     pub next_market_nonce: u64,
     pub next_signer_set_id: u64,
     pub default_reveal_delay_slots: u64,
+    pub max_reveal_delay_slots: u64, // reveal window after reveal_after_slot; later commits can be expired
+    pub active_signer_set_id: u64,   // 0 = none activated yet
+    pub pending_signer_set_id: u64,  // 0 = no rotation scheduled
+    pub max_signer_overlap_slots: u64,
+    pub dispute_window_slots: u64, // after finalize_epoch; close_epoch waits for it
+    pub min_dispute_bond_lamports: u64,
+    pub finalization_delay_ms: u64, // finalize_epoch allowed from window_end_ms + this
+    pub resolution_signer_set_id: u64, // 0 = none; signs resolve_market
     pub bump: u8,
 }
 
 impl ProtocolConfig {
-    pub const LEN: usize = 8 + 1 + 32 + 1 + 8 + 8 + 8 + 1;
+    pub const LEN: usize = 8 + 1 + 32 + (1 + 32) + 1 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 1;
 }
 
 pub fn protocol_pda() -> (Pubkey, u8) {
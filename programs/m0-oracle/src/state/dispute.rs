@@ -0,0 +1,62 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum DisputeReason {
+    IntegrityHashMismatch,
+    SignatureInvalid,
+    SignatureThresholdNotMet,
+    ReplayViolation,
+    SignerSetMismatch,
+    RevealMissing,
+    FinalizationDelayed,
+    MarketPausedViolation,
+    SchemaViolation,
+    ParameterViolation,
+    Other,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum DisputeState {
+    Open,
+    Accepted,
+    Rejected,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum CorrectionAction {
+    None,
+    Retract,
+    Supersede,
+}
+
+// One dispute per (epoch, target bundle hash): [DISPUTE_SEED, epoch, bundle_hash].
+// The bond is held as extra lamports on this account until resolution.
+// See docs/protocol-spec/dispute-resolution.md.
+#[account]
+pub struct DisputeRecord {
+    pub market: Pubkey,
+    pub epoch: Pubkey,
+    pub epoch_id: u64,
+    pub bundle_hash: [u8; 32],
+    pub reason: DisputeReason,
+    pub reporter: Pubkey,
+    pub evidence_hash: [u8; 32],
+    pub bond_lamports: u64,
+    pub opened_at_slot: u64,
+    pub state: DisputeState,
+    pub resolver: Pubkey,
+    pub resolved_at_slot: u64,
+    pub action: CorrectionAction,
+    pub corrective_bundle_hash: [u8; 32], // zero unless superseded
+    pub bump: u8,
+}
+
+impl DisputeRecord {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 32 + 1 + 32 + 32 + 8 + 8 + 1 + 32 + 8 + 1 + 32 + 1;
+}
+
+pub fn dispute_pda(epoch: &Pubkey, bundle_hash: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DISPUTE_SEED, epoch.as_ref(), bundle_hash], &crate::ID)
+}
@@ -1,17 +1,54 @@
 
 use anchor_lang::prelude::*;
+use crate::constants::FUTURE_SKEW_MS;
+use crate::error::M0OracleError;
+
+// Status of an epoch's published output; mirrored on LatestPrediction for consumers.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputStatus {
+    #[default]
+    Valid,
+    Disputed,
+    Retracted,
+    Superseded,
+}
 
 #[account]
 pub struct Epoch {
     pub market: Pubkey,
-    pub epoch_id: u64,
+    pub epoch_id: u64,    // epoch_index of the window
+    pub window_start_ms: u64, // inclusive
+    pub window_end_ms: u64,   // exclusive
     pub open: bool,
     pub opened_at_slot: u64,
     pub finalized_at_slot: u64,
     pub publish_sequence: u64, // replay protection for reveals
+    pub rent_payer: Pubkey,
+    pub commit_count: u32,
+    pub open_commits: u32, // commits not yet closed; close_epoch requires zero
+    pub missed_reveals: u32,
+    pub submission_count: u32, // aggregation mode: PublisherSubmission accounts written
+    pub output_status: OutputStatus,
+    pub superseded_by: [u8; 32], // corrective bundle hash when superseded
+    pub open_disputes: u32,
+    pub accepted_disputes: u32,
     pub bump: u8,
 }
 
 impl Epoch {
-    pub const LEN: usize = 8 + 32 + 8 + 1 + 8 + 8 + 8 + 1;
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 1 + 8 + 8 + 8 + 32 + 4 + 4 + 4 + 4 + 1 + 32 + 4 + 4 + 1;
+
+    // Commits only target the window the clock is in (epoch-rounding.md §7.1).
+    pub fn require_accepting_commits(&self, now_ms: u64) -> Result<()> {
+        if !self.open {
+            return err!(M0OracleError::EpochNotOpen);
+        }
+        if now_ms >= self.window_end_ms {
+            return err!(M0OracleError::EpochWindowStale);
+        }
+        if self.window_start_ms > now_ms.saturating_add(FUTURE_SKEW_MS) {
+            return err!(M0OracleError::EpochWindowInFuture);
+        }
+        Ok(())
+    }
 }
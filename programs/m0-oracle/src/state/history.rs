@@ -0,0 +1,56 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+#[zero_copy]
+#[derive(Default)]
+pub struct RevealHistoryEntry {
+    pub bundle_hash: [u8; 32], // content hash, or merkle root in chunk mode
+    pub sequence: u64,
+    pub signer_set_id: u64,
+    pub epoch_id: u64,
+    pub slot: u64,
+    pub revealer: Pubkey,
+}
+
+impl RevealHistoryEntry {
+    pub const LEN: usize = 32 + 8 + 8 + 8 + 8 + 32;
+}
+
+// Fixed-capacity ring buffer of reveals for one market: [HISTORY_SEED, market].
+// `head` is the next slot to write; once full, the oldest entry is overwritten.
+#[account(zero_copy)]
+pub struct RevealHistory {
+    pub market: Pubkey,
+    pub total_reveals: u64,
+    pub head: u32,
+    pub count: u32,
+    pub bump: u8,
+    pub _padding: [u8; 7],
+    pub entries: [RevealHistoryEntry; REVEAL_HISTORY_CAPACITY],
+}
+
+impl RevealHistory {
+    pub const LEN: usize = 8 + 32 + 8 + 4 + 4 + 1 + 7 + RevealHistoryEntry::LEN * REVEAL_HISTORY_CAPACITY;
+
+    pub fn push(&mut self, entry: RevealHistoryEntry) {
+        self.entries[self.head as usize] = entry;
+        self.head = (self.head + 1) % REVEAL_HISTORY_CAPACITY as u32;
+        if (self.count as usize) < REVEAL_HISTORY_CAPACITY {
+            self.count += 1;
+        }
+        self.total_reveals = self.total_reveals.saturating_add(1);
+    }
+
+    /// Up to `n` most recent entries, newest first.
+    pub fn recent(&self, n: usize) -> Vec<RevealHistoryEntry> {
+        let cap = REVEAL_HISTORY_CAPACITY;
+        (0..n.min(self.count as usize))
+            .map(|i| self.entries[(self.head as usize + cap - 1 - i) % cap])
+            .collect()
+    }
+}
+
+pub fn history_pda(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[HISTORY_SEED, market.as_ref()], &crate::ID)
+}
@@ -2,8 +2,9 @@
 use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::error::M0OracleError;
+use m0_registry::state::metadata::MarketMetadata;
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub enum Domain {
     Sports,
     Politics,
@@ -12,14 +13,37 @@ pub enum Domain {
     Custom,
 }
 
+impl Domain {
+    // Registry stores the domain as a string ("sports", "SPORTS", ...).
+    pub fn from_registry(domain: &str) -> Domain {
+        match domain.to_ascii_lowercase().as_str() {
+            "sports" => Domain::Sports,
+            "politics" => Domain::Politics,
+            "macro" => Domain::Macro,
+            "crypto" => Domain::Crypto,
+            _ => Domain::Custom,
+        }
+    }
+}
+
+// Mirrors an m0-registry MarketMetadata ([MARKET_META_SEED, market_id]); the registry is
+// the source of truth and changes are pulled in with sync_market.
 #[account]
 pub struct Market {
     pub market_id: String,
     pub domain: Domain,
     pub active: bool,
     pub outcomes: Vec<String>,
-    pub current_epoch_id: u64,
+    pub current_epoch_id: u64, // latest opened epoch index
+    pub epoch_window_ms: u64,
+    pub publish_cadence_ms: u64, // registry cadence_ms
+    pub tier_policy: String,
+    pub registry_updated_at_slot: u64, // MarketMetadata.updated_at_slot last applied
     pub last_sequence: u64,
+    pub min_publishers: u8, // 0 = single publisher; >0 = aggregate reveals at finalize_epoch
+    pub merkle_reveals: bool, // reveals use merkle chunk mode only; no LatestPrediction output
+    pub publish_fee_lamports: u64, // charged per commit, paid into the m0-fee-router FeeVault
+    pub resolved: bool,     // set by resolve_market; the market can't be re-activated
     pub bump: u8,
 }
 
@@ -39,6 +63,27 @@ impl Market {
         Ok(())
     }
 
+    // Spec §6.2; divisibility is required so every tick has the same length.
+    pub fn validate_cadence(epoch_window_ms: u64, publish_cadence_ms: u64) -> Result<()> {
+        if epoch_window_ms == 0 || publish_cadence_ms == 0 || publish_cadence_ms > epoch_window_ms || epoch_window_ms % publish_cadence_ms != 0 {
+            return err!(M0OracleError::InvalidEpochWindow);
+        }
+        Ok(())
+    }
+
+    /// Requires the registry entry to describe this market: same domain, outcomes
+    /// (same order), cadence and tier policy.
+    pub fn validate_registry(meta: &MarketMetadata, domain: Domain, outcomes: &[String], publish_cadence_ms: u64, tier_policy: &str) -> Result<()> {
+        if Domain::from_registry(&meta.domain) != domain
+            || meta.outcomes.as_slice() != outcomes
+            || meta.cadence_ms as u64 != publish_cadence_ms
+            || meta.tier_policy != tier_policy
+        {
+            return err!(M0OracleError::RegistryMismatch);
+        }
+        Ok(())
+    }
+
     pub fn len_with(outcome_count: usize, market_id_len: usize) -> usize {
         // Anchor account discriminator (8) is included by the runtime.
         // LEN here is for space allocation: 8 + fields.
@@ -47,8 +92,16 @@ impl Market {
         // active: 1
         // outcomes: 4 + outcome_count*(4 + bytes)
         // current_epoch_id: 8
+        // epoch_window_ms: 8
+        // publish_cadence_ms: 8
+        // tier_policy: 4 + MAX_TIER_POLICY_LEN
+        // registry_updated_at_slot: 8
         // last_sequence: 8
+        // min_publishers: 1
+        // merkle_reveals: 1
+        // publish_fee_lamports: 8
+        // resolved: 1
         // bump: 1
-        8 + 4 + market_id_len + 1 + 1 + 4 + outcome_count * (4 + MAX_OUTCOME_ID_LEN) + 8 + 8 + 1
+        8 + 4 + market_id_len + 1 + 1 + 4 + outcome_count * (4 + MAX_OUTCOME_ID_LEN) + 8 + 8 + 8 + 4 + MAX_TIER_POLICY_LEN + 8 + 8 + 1 + 1 + 8 + 1 + 1
     }
 }
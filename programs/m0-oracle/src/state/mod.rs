@@ -1,8 +1,15 @@
 
 pub mod audit;
+pub mod chunked;
 pub mod commit;
 pub mod config;
+pub mod dispute;
 pub mod epoch;
+pub mod history;
 pub mod market;
+pub mod prediction;
+pub mod resolution;
 pub mod reveal;
+pub mod role;
 pub mod signer_set;
+pub mod submission;
@@ -0,0 +1,113 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::state::epoch::OutputStatus;
+use crate::state::market::Market;
+use crate::state::reveal::MarketReveal;
+use crate::state::submission::PublisherSubmission;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct PredictedOutcome {
+    pub outcome_index: u8, // index into Market.outcomes
+    pub p_scaled: u64,
+    pub ci_low_scaled: u64,
+    pub ci_high_scaled: u64,
+    pub ci_level_bps: u16,
+    pub quality_flags: u32,
+}
+
+impl PredictedOutcome {
+    pub const LEN: usize = 1 + 8 + 8 + 8 + 2 + 4;
+}
+
+// Consumer-readable copy of the most recent revealed output for a market.
+// One PDA per market: [PREDICTION_SEED, market].
+#[account]
+#[derive(Default)]
+pub struct LatestPrediction {
+    pub market: Pubkey,
+    pub epoch_id: u64,
+    pub sequence: u64,
+    pub signer_set_id: u64,
+    pub bundle_hash: [u8; 32],
+    pub observed_at_ms: u64,
+    pub risk_score: u16,
+    pub quality_flags: u32,
+    pub updated_at_slot: u64,
+    pub publisher_count: u8, // 1 for a direct reveal; >1 when aggregated at finalize_epoch
+    pub status: OutputStatus,   // consumers must not use Retracted/Superseded outputs
+    pub superseded_by: [u8; 32],
+    pub outcomes: Vec<PredictedOutcome>,
+    pub bump: u8,
+}
+
+impl LatestPrediction {
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 32 + 8 + 2 + 4 + 8 + 1 + 1 + 32 + 4 + MAX_OUTCOMES * PredictedOutcome::LEN + 1;
+
+    /// Overwrites the stored output with a validated market reveal.
+    /// Outcomes are stored in Market.outcomes order; `mr` must already have passed
+    /// verify::bundle::validate_market_reveal.
+    pub fn apply_reveal(&mut self, market: &Market, mr: &MarketReveal, signer_set_id: u64, bundle_hash: [u8; 32], sequence: u64, slot: u64) {
+        self.epoch_id = mr.epoch_id;
+        self.sequence = sequence;
+        self.signer_set_id = signer_set_id;
+        self.bundle_hash = bundle_hash;
+        self.observed_at_ms = mr.observed_at_ms;
+        self.risk_score = mr.risk_score;
+        self.quality_flags = mr.quality_flags;
+        self.updated_at_slot = slot;
+        self.publisher_count = 1;
+        self.status = OutputStatus::Valid;
+        self.superseded_by = [0u8; 32];
+        self.outcomes = predicted_outcomes(market, mr);
+    }
+
+    /// Mirrors an epoch's output status; ignored once a later epoch has been published.
+    pub fn mark_status(&mut self, epoch_id: u64, status: OutputStatus, superseded_by: [u8; 32]) {
+        if self.epoch_id == epoch_id {
+            self.status = status;
+            self.superseded_by = superseded_by;
+        }
+    }
+
+    /// Overwrites the stored output with the aggregate of an epoch's publisher submissions.
+    pub fn apply_aggregate(&mut self, epoch_id: u64, sequence: u64, subs: &[PublisherSubmission], aggregate_hash: [u8; 32], outcomes: Vec<PredictedOutcome>, slot: u64) {
+        let first_set = subs.first().map(|s| s.signer_set_id).unwrap_or(0);
+        self.epoch_id = epoch_id;
+        self.sequence = sequence;
+        // 0 when publishers signed under different sets
+        self.signer_set_id = if subs.iter().all(|s| s.signer_set_id == first_set) { first_set } else { 0 };
+        self.bundle_hash = aggregate_hash;
+        self.observed_at_ms = subs.iter().map(|s| s.observed_at_ms).max().unwrap_or(0);
+        self.risk_score = subs.iter().map(|s| s.risk_score).max().unwrap_or(0);
+        self.quality_flags = subs.iter().fold(0, |acc, s| acc | s.quality_flags);
+        self.updated_at_slot = slot;
+        self.publisher_count = subs.len() as u8;
+        self.status = OutputStatus::Valid;
+        self.superseded_by = [0u8; 32];
+        self.outcomes = outcomes;
+    }
+}
+
+/// Outcomes of a validated market reveal in Market.outcomes order.
+pub fn predicted_outcomes(market: &Market, mr: &MarketReveal) -> Vec<PredictedOutcome> {
+    market
+        .outcomes
+        .iter()
+        .enumerate()
+        .filter_map(|(i, id)| {
+            mr.outcomes.iter().find(|o| &o.outcome_id == id).map(|o| PredictedOutcome {
+                outcome_index: i as u8,
+                p_scaled: o.p_scaled,
+                ci_low_scaled: o.ci_low_scaled,
+                ci_high_scaled: o.ci_high_scaled,
+                ci_level_bps: o.ci_level_bps,
+                quality_flags: o.quality_flags,
+            })
+        })
+        .collect()
+}
+
+pub fn prediction_pda(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PREDICTION_SEED, market.as_ref()], &crate::ID)
+}
@@ -0,0 +1,25 @@
+
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ResolutionOutcome {
+    Winner { outcome_index: u8 }, // index into Market.outcomes
+    Void,                         // cancelled / no contest; nothing settles as a win
+}
+
+// Final, immutable result of a market: [RESOLUTION_SEED, market].
+// Written once by resolve_market under the resolution signer set.
+#[account]
+pub struct MarketResolution {
+    pub market: Pubkey,
+    pub outcome: ResolutionOutcome,
+    pub resolution_source_hash: [u8; 32], // sha256 of the source evidence (feed record, official result)
+    pub signer_set_id: u64,
+    pub resolution_slot: u64,
+    pub resolver: Pubkey, // fee payer that submitted the signatures
+    pub bump: u8,
+}
+
+impl MarketResolution {
+    pub const LEN: usize = 8 + 32 + (1 + 1) + 32 + 8 + 8 + 32 + 1;
+}
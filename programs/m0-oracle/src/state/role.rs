@@ -0,0 +1,73 @@
+
+use anchor_lang::prelude::*;
+use crate::state::market::Domain;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Admin,          // may act in any non-admin role within its scope and grant/revoke non-admin roles
+    MarketOperator, // create/update markets
+    Publisher,      // commit/reveal predictions
+    Pauser,         // pause the protocol (resume stays with admin)
+    DisputeResolver, // resolve disputes and apply corrections (e.g. a governance PDA)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum RoleScope {
+    Global,
+    Domain(Domain),
+    Market(Pubkey),
+}
+
+impl RoleScope {
+    /// PDA seed components: a tag byte, then 32 bytes of scope data
+    /// (kept separate since a single seed is capped at 32 bytes).
+    pub fn seed_tag(&self) -> [u8; 1] {
+        match self {
+            RoleScope::Global => [0],
+            RoleScope::Domain(_) => [1],
+            RoleScope::Market(_) => [2],
+        }
+    }
+
+    pub fn seed_payload(&self) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        match self {
+            RoleScope::Global => {}
+            RoleScope::Domain(d) => out[0] = *d as u8,
+            RoleScope::Market(m) => out.copy_from_slice(m.as_ref()),
+        }
+        out
+    }
+
+    /// A `None` target only matches a global scope.
+    pub fn covers(&self, market: Option<&Pubkey>, domain: Option<&Domain>) -> bool {
+        match self {
+            RoleScope::Global => true,
+            RoleScope::Domain(d) => domain == Some(d),
+            RoleScope::Market(m) => market == Some(m),
+        }
+    }
+}
+
+// One PDA per (role, grantee, scope):
+// [ROLE_SEED, role, grantee, scope.seed_tag(), scope.seed_payload()].
+// Revocation closes the account.
+#[account]
+pub struct RoleAssignment {
+    pub role: Role,
+    pub grantee: Pubkey,
+    pub scope: RoleScope,
+    pub granted_by: Pubkey,
+    pub granted_at_slot: u64,
+    pub weight: u16, // Publisher weight in multi-publisher aggregation
+    pub bump: u8,
+}
+
+impl RoleAssignment {
+    // scope: 1 tag + 32 max payload
+    pub const LEN: usize = 8 + 1 + 32 + (1 + 32) + 32 + 8 + 2 + 1;
+
+    pub fn grants(&self, required: Role) -> bool {
+        self.role == required || (self.role == Role::Admin && required != Role::Admin)
+    }
+}
@@ -2,13 +2,23 @@
 use anchor_lang::prelude::*;
 use crate::error::M0OracleError;
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SignerSetPurpose {
+    Publish,    // signs bundles; rotated via rotate_signer_set
+    Resolution, // signs market resolutions; set via set_resolution_signer_set
+}
+
 #[account]
 pub struct SignerSet {
     pub signer_set_id: u64,
     pub threshold: u16,
     pub pubkeys: Vec<Pubkey>,
-    pub active: bool,
+    pub active: bool, // cleared by deactivate_signer_set; never set again
     pub created_at_slot: u64,
+    pub activation_slot: u64,
+    pub expires_at_slot: u64, // 0 = no expiry; set when a successor is scheduled
+    pub deactivated_at_slot: u64,
+    pub purpose: SignerSetPurpose,
     pub bump: u8,
 }
 
@@ -22,6 +32,24 @@ impl SignerSet {
 
     pub fn len_with(pubkeys_len: usize) -> usize {
         // pubkeys: 4 + 32*N
-        8 + 8 + 2 + 4 + 32 * pubkeys_len + 1 + 8 + 1
+        8 + 8 + 2 + 4 + 32 * pubkeys_len + 1 + 8 + 8 + 8 + 8 + 1 + 1
+    }
+
+    pub fn is_valid_at(&self, slot: u64) -> bool {
+        self.active && slot >= self.activation_slot && (self.expires_at_slot == 0 || slot < self.expires_at_slot)
+    }
+
+    /// Checks that this set is the one named by the bundle and may sign at `slot`.
+    pub fn require_usable(&self, signer_set_id: u64, slot: u64) -> Result<()> {
+        if signer_set_id != self.signer_set_id || self.purpose != SignerSetPurpose::Publish {
+            return err!(M0OracleError::SignerSetMismatch);
+        }
+        if self.expires_at_slot != 0 && slot >= self.expires_at_slot {
+            return err!(M0OracleError::SignerSetExpired);
+        }
+        if !self.is_valid_at(slot) {
+            return err!(M0OracleError::SignerSetNotActive);
+        }
+        Ok(())
     }
 }
@@ -0,0 +1,30 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::state::prediction::PredictedOutcome;
+
+// One publisher's revealed output for an epoch in aggregation mode:
+// [SUBMISSION_SEED, epoch, publisher]. Combined by finalize_epoch.
+#[account]
+pub struct PublisherSubmission {
+    pub epoch: Pubkey,
+    pub publisher: Pubkey,
+    pub weight: u16,
+    pub signer_set_id: u64,
+    pub bundle_hash: [u8; 32],
+    pub sequence: u64,
+    pub observed_at_ms: u64,
+    pub risk_score: u16,
+    pub quality_flags: u32,
+    pub revealed_at_slot: u64,
+    pub outcomes: Vec<PredictedOutcome>, // Market.outcomes order
+    pub bump: u8,
+}
+
+impl PublisherSubmission {
+    pub const LEN: usize = 8 + 32 + 32 + 2 + 8 + 32 + 8 + 8 + 2 + 4 + 8 + 4 + MAX_OUTCOMES * PredictedOutcome::LEN + 1;
+}
+
+pub fn submission_pda(epoch: &Pubkey, publisher: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SUBMISSION_SEED, epoch.as_ref(), publisher.as_ref()], &crate::ID)
+}
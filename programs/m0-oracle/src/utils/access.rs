@@ -0,0 +1,39 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::DEFAULT_PUBLISHER_WEIGHT;
+use crate::error::M0OracleError;
+use crate::state::config::ProtocolConfig;
+use crate::state::market::Domain;
+use crate::state::role::{Role, RoleAssignment};
+
+// Role checks shared by instructions.
+// ProtocolConfig.authority holds every role implicitly. Anyone else must pass a
+// RoleAssignment granting `required` with a scope covering the target.
+// Assignments are only written by grant_role, so program ownership is enough to
+// trust their fields.
+
+pub fn require_role(
+    cfg: &ProtocolConfig,
+    signer: &Pubkey,
+    assignment: Option<&RoleAssignment>,
+    required: Role,
+    market: Option<&Pubkey>,
+    domain: Option<&Domain>,
+) -> Result<()> {
+    if *signer == cfg.authority {
+        return Ok(());
+    }
+    let a = assignment.ok_or(M0OracleError::Unauthorized)?;
+    if a.grantee != *signer || !a.grants(required) || !a.scope.covers(market, domain) {
+        return err!(M0OracleError::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Aggregation weight of a publisher that already passed `require_role`.
+pub fn publisher_weight(signer: &Pubkey, assignment: Option<&RoleAssignment>) -> u16 {
+    match assignment {
+        Some(a) if a.grantee == *signer => a.weight.max(1),
+        _ => DEFAULT_PUBLISHER_WEIGHT,
+    }
+}
@@ -0,0 +1,89 @@
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::error::M0OracleError;
+
+// Helpers for program accounts passed through remaining_accounts (batch instructions),
+// where Anchor's typed constraints and init are not available.
+
+/// Deserializes a program-owned account; the discriminator is checked by try_deserialize.
+pub fn load<T: AccountDeserialize>(info: &AccountInfo) -> Result<T> {
+    if info.owner != &crate::ID {
+        return err!(M0OracleError::InvalidBatch);
+    }
+    let data = info.try_borrow_data()?;
+    T::try_deserialize(&mut &data[..])
+}
+
+/// Writes `value` (discriminator included) back into the account data.
+pub fn store<T: AccountSerialize>(info: &AccountInfo, value: &T) -> Result<()> {
+    if !info.is_writable {
+        return err!(M0OracleError::InvalidBatch);
+    }
+    let mut data = info.try_borrow_mut_data()?;
+    let mut w: &mut [u8] = &mut data[..];
+    value.try_serialize(&mut w)
+}
+
+/// Requires `info` to be the PDA for `seeds` and returns its bump.
+pub fn require_pda(info: &AccountInfo, seeds: &[&[u8]]) -> Result<u8> {
+    let (expected, bump) = Pubkey::find_program_address(seeds, &crate::ID);
+    if expected != info.key() {
+        return err!(M0OracleError::InvalidBatch);
+    }
+    Ok(bump)
+}
+
+/// Creates a rent-exempt program-owned PDA, like `init`. A pre-funded address is
+/// topped up, allocated and assigned instead.
+pub fn create_pda<'info>(
+    payer: &AccountInfo<'info>,
+    target: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    if target.owner != &system_program::ID || !target.data_is_empty() {
+        return err!(M0OracleError::InvalidBatch);
+    }
+
+    let rent = Rent::get()?.minimum_balance(space);
+    let current = target.lamports();
+    let signer = &[signer_seeds];
+
+    if current == 0 {
+        let accounts = system_program::CreateAccount { from: payer.clone(), to: target.clone() };
+        system_program::create_account(CpiContext::new_with_signer(system_program.clone(), accounts, signer), rent, space as u64, &crate::ID)?;
+        return Ok(());
+    }
+
+    if rent > current {
+        let accounts = system_program::Transfer { from: payer.clone(), to: target.clone() };
+        system_program::transfer(CpiContext::new(system_program.clone(), accounts), rent - current)?;
+    }
+    let accounts = system_program::Allocate { account_to_allocate: target.clone() };
+    system_program::allocate(CpiContext::new_with_signer(system_program.clone(), accounts, signer), space as u64)?;
+    let accounts = system_program::Assign { account_to_assign: target.clone() };
+    system_program::assign(CpiContext::new_with_signer(system_program.clone(), accounts, signer), &crate::ID)?;
+    Ok(())
+}
+
+/// `init_if_needed` counterpart: loads the PDA for `seeds`, or creates it and returns
+/// `T::default()`. Returns the bump either way.
+pub fn load_or_create<'info, T: AccountDeserialize + Default>(
+    payer: &AccountInfo<'info>,
+    target: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    seeds: &[&[u8]],
+) -> Result<(T, u8)> {
+    let bump = require_pda(target, seeds)?;
+    if target.owner == &crate::ID {
+        return Ok((load(target)?, bump));
+    }
+    let bump_seed = [bump];
+    let mut signer_seeds = seeds.to_vec();
+    signer_seeds.push(&bump_seed);
+    create_pda(payer, target, system_program, space, &signer_seeds)?;
+    Ok((T::default(), bump))
+}
@@ -0,0 +1,78 @@
+
+use crate::constants::PROB_SCALE;
+use crate::state::prediction::PredictedOutcome;
+use crate::state::submission::PublisherSubmission;
+
+// Multi-publisher aggregation (finalize_epoch).
+// Per outcome: weighted median of p_scaled, CI envelope (min low / max high),
+// weakest ci_level_bps and the union of quality flags. Independent medians need not
+// sum to PROB_SCALE, so they are rescaled afterwards and each CI widened to contain
+// its rescaled point.
+
+/// Lower weighted median: the smallest value whose cumulative weight reaches half the total.
+pub fn weighted_median(points: &mut [(u64, u64)]) -> u64 {
+    points.sort_unstable();
+    let total: u64 = points.iter().map(|(_, w)| *w).sum();
+    let mut acc = 0u64;
+    for (v, w) in points.iter() {
+        acc += *w;
+        if acc * 2 >= total {
+            return *v;
+        }
+    }
+    0
+}
+
+/// Rescales `values` to sum to PROB_SCALE (largest remainder; ties go to the lower index).
+/// An all-zero input is spread evenly.
+pub fn rescale_to_prob_scale(values: &[u64]) -> Vec<u64> {
+    let n = values.len() as u128;
+    if n == 0 {
+        return Vec::new();
+    }
+    let total: u128 = values.iter().map(|v| *v as u128).sum();
+    let (weights, total) = if total == 0 { (vec![1u128; values.len()], n) } else { (values.iter().map(|v| *v as u128).collect(), total) };
+
+    let scale = PROB_SCALE as u128;
+    let mut out: Vec<u64> = weights.iter().map(|w| (w * scale / total) as u64).collect();
+    let assigned: u64 = out.iter().sum();
+
+    let mut by_remainder: Vec<(u128, usize)> = weights.iter().enumerate().map(|(i, w)| (w * scale % total, i)).collect();
+    by_remainder.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    for (_, i) in by_remainder.into_iter().take((PROB_SCALE - assigned) as usize) {
+        out[i] += 1;
+    }
+    out
+}
+
+pub fn aggregate_outcomes(subs: &[PublisherSubmission], outcome_count: usize) -> Vec<PredictedOutcome> {
+    let mut outcomes: Vec<PredictedOutcome> = (0..outcome_count)
+        .filter_map(|i| {
+            let entries: Vec<(&PredictedOutcome, u16)> = subs
+                .iter()
+                .filter_map(|s| s.outcomes.iter().find(|o| o.outcome_index as usize == i).map(|o| (o, s.weight)))
+                .collect();
+            if entries.is_empty() {
+                return None;
+            }
+
+            let mut points: Vec<(u64, u64)> = entries.iter().map(|(o, w)| (o.p_scaled, *w as u64)).collect();
+            Some(PredictedOutcome {
+                outcome_index: i as u8,
+                p_scaled: weighted_median(&mut points),
+                ci_low_scaled: entries.iter().map(|(o, _)| o.ci_low_scaled).min().unwrap_or(0),
+                ci_high_scaled: entries.iter().map(|(o, _)| o.ci_high_scaled).max().unwrap_or(0),
+                ci_level_bps: entries.iter().map(|(o, _)| o.ci_level_bps).min().unwrap_or(0),
+                quality_flags: entries.iter().fold(0, |acc, (o, _)| acc | o.quality_flags),
+            })
+        })
+        .collect();
+
+    let medians: Vec<u64> = outcomes.iter().map(|o| o.p_scaled).collect();
+    for (o, p) in outcomes.iter_mut().zip(rescale_to_prob_scale(&medians)) {
+        o.p_scaled = p;
+        o.ci_low_scaled = o.ci_low_scaled.min(p);
+        o.ci_high_scaled = o.ci_high_scaled.max(p);
+    }
+    outcomes
+}
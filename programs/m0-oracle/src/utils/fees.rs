@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::error::M0OracleError;
+use m0_fee_router::cpi::accounts::Deposit;
+use m0_fee_router::program::M0FeeRouter;
+use m0_fee_router::state::ledger::FeeLedger;
+use m0_fee_router::state::router::Router;
+use m0_fee_router::state::vaults::FeeVault;
+
+/// Accounts for m0-fee-router `deposit` into the native SOL ledger.
+pub struct FeeAccounts<'info> {
+    pub router: AccountInfo<'info>,
+    pub vault: AccountInfo<'info>,
+    pub ledger: AccountInfo<'info>,
+    pub program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> FeeAccounts<'info> {
+    /// The fee-router accounts are optional on commit; `None` unless all of them were passed.
+    pub fn from_optional(
+        router: Option<&Account<'info, Router>>,
+        vault: Option<&Account<'info, FeeVault>>,
+        ledger: Option<&Account<'info, FeeLedger>>,
+        program: Option<&Program<'info, M0FeeRouter>>,
+        system_program: &Program<'info, System>,
+    ) -> Option<Self> {
+        Some(Self {
+            router: router?.to_account_info(),
+            vault: vault?.to_account_info(),
+            ledger: ledger?.to_account_info(),
+            program: program?.to_account_info(),
+            system_program: system_program.to_account_info(),
+        })
+    }
+}
+
+/// Pays the market's publish fee from the committer through m0-fee-router `deposit`,
+/// which credits every route destination's share at deposit time.
+/// Returns the fee vault when a fee was charged; the accounts are only required then.
+pub fn charge_publish_fee<'info>(payer: &AccountInfo<'info>, fee: Option<&FeeAccounts<'info>>, amount: u64) -> Result<Option<Pubkey>> {
+    if amount == 0 {
+        return Ok(None);
+    }
+    let fee = fee.ok_or(M0OracleError::FeeAccountsRequired)?;
+    let accounts = Deposit {
+        depositor: payer.clone(),
+        router: fee.router.clone(),
+        vault: fee.vault.clone(),
+        ledger: fee.ledger.clone(),
+        depositor_token: None,
+        vault_token: None,
+        token_program: None,
+        system_program: fee.system_program.clone(),
+    };
+    m0_fee_router::cpi::deposit(CpiContext::new(fee.program.clone(), accounts), amount)?;
+    Ok(Some(fee.vault.key()))
+}
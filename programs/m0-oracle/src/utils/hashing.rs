@@ -21,12 +21,85 @@ pub fn hash_bundle_content(payload: &[u8]) -> [u8; 32] {
     h.finalize().into()
 }
 
-pub fn hash_signature_message(bundle_content_hash: &[u8; 32], signer_set_id: u64, publish_epoch_id: u64, sequence: u64) -> [u8; 32] {
+// Epoch ids are shared time windows and every epoch's sequence starts at 1, so the
+// market and epoch PDAs keep signatures for different markets from colliding.
+pub fn hash_signature_message(bundle_content_hash: &[u8; 32], market: &Pubkey, epoch: &Pubkey, signer_set_id: u64, publish_epoch_id: u64, sequence: u64) -> [u8; 32] {
     let mut h = Sha256::new();
-    h.update(b"M0_SIGMSG_V1");
+    h.update(b"M0_SIGMSG_V2");
     h.update(bundle_content_hash);
+    h.update(market.as_ref());
+    h.update(epoch.as_ref());
     h.update(signer_set_id.to_le_bytes());
     h.update(publish_epoch_id.to_le_bytes());
     h.update(sequence.to_le_bytes());
     h.finalize().into()
 }
+
+// Batch reveals sign once for the whole bundle; each market's (market, epoch, sequence)
+// is bound in bundle market order.
+pub fn hash_batch_signature_message(bundle_content_hash: &[u8; 32], signer_set_id: u64, publish_epoch_id: u64, targets: &[(Pubkey, Pubkey, u64)]) -> [u8; 32] {
+    let mut h = Sha256::new();
+    h.update(b"M0_BATCH_SIGMSG_V2");
+    h.update(bundle_content_hash);
+    h.update(signer_set_id.to_le_bytes());
+    h.update(publish_epoch_id.to_le_bytes());
+    h.update((targets.len() as u32).to_le_bytes());
+    for (market, epoch, sequence) in targets {
+        h.update(market.as_ref());
+        h.update(epoch.as_ref());
+        h.update(sequence.to_le_bytes());
+    }
+    h.finalize().into()
+}
+
+// Message signed by the resolution signer set; `outcome` is the Borsh encoding of
+// ResolutionOutcome.
+pub fn hash_resolution_message(market_id: &str, outcome: &[u8], resolution_source_hash: &[u8; 32], signer_set_id: u64) -> [u8; 32] {
+    let mut h = Sha256::new();
+    h.update(b"M0_RESOLUTION_V1");
+    h.update((market_id.len() as u32).to_le_bytes());
+    h.update(market_id.as_bytes());
+    h.update(outcome);
+    h.update(resolution_source_hash);
+    h.update(signer_set_id.to_le_bytes());
+    h.finalize().into()
+}
+
+// Identifies an aggregated output by the bundles it was built from (sorted by publisher).
+pub fn hash_aggregate(bundle_hashes: &[[u8; 32]]) -> [u8; 32] {
+    let mut h = Sha256::new();
+    h.update(b"M0_AGGREGATE_V1");
+    for b in bundle_hashes {
+        h.update(b);
+    }
+    h.finalize().into()
+}
+
+// Merkle chunk mode (docs/engine-spec/bundle-hashing.md, section 6).
+// Must stay byte-identical with m0_bundle::merkle.
+
+pub fn hash_chunk(chunk_index: u32, chunk: &[u8]) -> [u8; 32] {
+    let mut h = Sha256::new();
+    h.update(b"M0_CHUNK_V1");
+    h.update(chunk_index.to_le_bytes());
+    h.update(chunk);
+    h.finalize().into()
+}
+
+pub fn hash_merkle_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut h = Sha256::new();
+    h.update(b"M0_MERKLE_NODE_V1");
+    h.update(left);
+    h.update(right);
+    h.finalize().into()
+}
+
+// Stands in for the bundle content hash in the commit and signer message, so the
+// chunk count is fixed at commit time along with the root.
+pub fn hash_merkle_commitment(merkle_root: &[u8; 32], chunk_count: u32) -> [u8; 32] {
+    let mut h = Sha256::new();
+    h.update(b"M0_MERKLE_COMMITMENT_V1");
+    h.update(merkle_root);
+    h.update(chunk_count.to_le_bytes());
+    h.finalize().into()
+}
@@ -1,4 +1,8 @@
 
+pub mod access;
+pub mod accounts;
+pub mod aggregate;
+pub mod fees;
 pub mod hashing;
 pub mod serialization;
 pub mod time;
@@ -1,6 +1,27 @@
 
 use anchor_lang::prelude::*;
+use crate::constants::EPOCH_ORIGIN_MS;
 
 pub fn now_slot() -> u64 {
     Clock::get().map(|c| c.slot).unwrap_or(0)
 }
+
+/// Cluster unix time in ms (second granularity).
+pub fn unix_ms(clock: &Clock) -> u64 {
+    (clock.unix_timestamp.max(0) as u64).saturating_mul(1000)
+}
+
+/// Epoch window containing `t_ms`, per docs/protocol-spec/epoch-rounding.md §4:
+/// `window_start_ms <= t_ms < window_end_ms`. Returns (epoch_index, start, end).
+pub fn epoch_bounds(t_ms: u64, epoch_window_ms: u64) -> (u64, u64, u64) {
+    let window = epoch_window_ms.max(1);
+    let index = t_ms.saturating_sub(EPOCH_ORIGIN_MS) / window;
+    let start = EPOCH_ORIGIN_MS + index * window;
+    (index, start, start.saturating_add(window))
+}
+
+/// Bounds of an explicit epoch index; inverse of `epoch_bounds`.
+pub fn window_of(epoch_index: u64, epoch_window_ms: u64) -> (u64, u64) {
+    let start = EPOCH_ORIGIN_MS.saturating_add(epoch_index.saturating_mul(epoch_window_ms));
+    (start, start.saturating_add(epoch_window_ms))
+}
@@ -0,0 +1,85 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::M0OracleError;
+use crate::state::market::Market;
+use crate::state::reveal::{BundleReveal, MarketReveal};
+
+// Content checks for revealed bundles.
+// The canonical on-chain encoding of a bundle is the Borsh serialization of BundleReveal;
+// the engine produces the same bytes via m0_bundle::codec::encode_canonical.
+
+/// Decodes `bundle_bytes` and requires it to be exactly the encoding of `bundle`.
+pub fn decode_and_match(bundle_bytes: &[u8], bundle: &BundleReveal) -> Result<()> {
+    BundleReveal::try_from_slice(bundle_bytes).map_err(|_| M0OracleError::BundleHashMismatch)?;
+    let expected = bundle.try_to_vec().map_err(|_| M0OracleError::BundleHashMismatch)?;
+    if expected.as_slice() != bundle_bytes {
+        return err!(M0OracleError::BundleHashMismatch);
+    }
+    Ok(())
+}
+
+/// Returns the single entry for `market_id`; a bundle may not list a market twice.
+pub fn find_market_reveal<'a>(bundle: &'a BundleReveal, market_id: &str) -> Result<&'a MarketReveal> {
+    let mut found: Option<&MarketReveal> = None;
+    for mr in &bundle.markets {
+        if mr.market_id == market_id {
+            if found.is_some() {
+                return err!(M0OracleError::InvalidMarketId);
+            }
+            found = Some(mr);
+        }
+    }
+    found.ok_or_else(|| error!(M0OracleError::InvalidMarketId))
+}
+
+/// The signed `publish_epoch_id` must be the epoch the reveal is written into.
+pub fn require_publish_epoch(publish_epoch_id: u64, epoch_id: u64) -> Result<()> {
+    if publish_epoch_id != epoch_id {
+        return err!(M0OracleError::EpochMismatch);
+    }
+    Ok(())
+}
+
+/// Checks one market entry against the market account and the epoch it is revealed into.
+pub fn validate_market_reveal(market: &Market, epoch_id: u64, mr: &MarketReveal) -> Result<()> {
+    if mr.market_id != market.market_id {
+        return err!(M0OracleError::InvalidMarketId);
+    }
+    if mr.epoch_id != epoch_id {
+        return err!(M0OracleError::EpochMismatch);
+    }
+
+    // outcome ids: same set as the market, no duplicates
+    if mr.outcomes.len() != market.outcomes.len() {
+        return err!(M0OracleError::InvalidOutcomeId);
+    }
+    for (i, o) in mr.outcomes.iter().enumerate() {
+        if !market.outcomes.iter().any(|m| m == &o.outcome_id) {
+            return err!(M0OracleError::InvalidOutcomeId);
+        }
+        if mr.outcomes[..i].iter().any(|p| p.outcome_id == o.outcome_id) {
+            return err!(M0OracleError::InvalidOutcomeId);
+        }
+    }
+
+    // probabilities: bounded, CI contains the point, sum to PROB_SCALE
+    let mut sum: u64 = 0;
+    for o in &mr.outcomes {
+        if o.ci_high_scaled > PROB_SCALE || o.ci_low_scaled > o.p_scaled || o.p_scaled > o.ci_high_scaled {
+            return err!(M0OracleError::InvalidProbabilityScale);
+        }
+        if o.ci_level_bps > 10_000 {
+            return err!(M0OracleError::InvalidProbabilityScale);
+        }
+        sum = sum.saturating_add(o.p_scaled);
+    }
+
+    // Each p_scaled is rounded independently off-chain, so allow one unit per outcome.
+    let tolerance = mr.outcomes.len() as u64 * PROB_SUM_TOLERANCE_PER_OUTCOME;
+    if sum.abs_diff(PROB_SCALE) > tolerance {
+        return err!(M0OracleError::InvalidProbabilityScale);
+    }
+
+    Ok(())
+}
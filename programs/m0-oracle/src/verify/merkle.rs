@@ -1,8 +1,15 @@
+use crate::utils::hashing::{hash_chunk, hash_merkle_node};
 
-// Merkle verification helpers (optional feature).
-// This file provides a minimal placeholder API so the program compiles.
-// Implement merkle chunking if you use "merkle_chunks" reveal mode.
+// Merkle proof verification for "merkle_chunks" reveal mode.
+// The proof is the list of sibling hashes from leaf to root; the chunk index
+// selects left/right ordering at each level.
 
-pub fn verify_merkle_proof(_leaf: &[u8], _root: &[u8; 32], _proof: &[u8]) -> bool {
-    false
+pub fn verify_merkle_proof(chunk_index: u32, chunk: &[u8], root: &[u8; 32], proof: &[[u8; 32]]) -> bool {
+    let mut acc = hash_chunk(chunk_index, chunk);
+    let mut idx = chunk_index;
+    for sibling in proof {
+        acc = if idx & 1 == 0 { hash_merkle_node(&acc, sibling) } else { hash_merkle_node(sibling, &acc) };
+        idx /= 2;
+    }
+    idx == 0 && &acc == root
 }
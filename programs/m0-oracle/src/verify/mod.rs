@@ -1,4 +1,5 @@
 
+pub mod bundle;
 pub mod signature;
 pub mod merkle;
 pub mod vrf;
@@ -1,21 +1,137 @@
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::load_instruction_at_checked;
 use crate::error::M0OracleError;
 
-// NOTE:
-// Proper on-chain signature verification is typically done by including
-// an Ed25519Program instruction in the same transaction and having this
-// program parse the instruction sysvar to ensure expected message+pubkeys.
-// This repo skeleton provides a compile-safe placeholder verifier.
+// Threshold signature verification.
 //
-// For production:
-/// - require the ed25519 verify instructions are present in the tx
-/// - parse sysvar::instructions and match pubkey+message hash
-/// - enforce threshold signatures
-pub fn verify_threshold_signatures_placeholder(_message_hash: &[u8; 32], signer_pubkeys: &[Pubkey], threshold: u16) -> Result<()> {
+// Signatures are not verified by this program directly. The transaction must carry
+// one or more Ed25519Program instructions; the runtime rejects the whole transaction
+// if any of them fails. This program then reads the instructions sysvar to confirm
+// that those verified signatures cover the expected message and come from distinct
+// members of the signer set.
+
+// Ed25519Program instruction layout (see solana_sdk::ed25519_instruction):
+//   u8  num_signatures
+//   u8  padding
+//   num_signatures * Ed25519SignatureOffsets (7 x u16 LE)
+const ED25519_HEADER_LEN: usize = 2;
+const ED25519_OFFSETS_LEN: usize = 14;
+const ED25519_PUBKEY_LEN: usize = 32;
+const ED25519_SIGNATURE_LEN: usize = 64;
+
+// Instruction index value meaning "the Ed25519 instruction itself".
+const ED25519_SELF_INDEX: u16 = u16::MAX;
+
+struct Ed25519Offsets {
+    signature_offset: u16,
+    signature_instruction_index: u16,
+    public_key_offset: u16,
+    public_key_instruction_index: u16,
+    message_data_offset: u16,
+    message_data_size: u16,
+    message_instruction_index: u16,
+}
+
+fn read_u16(data: &[u8], at: usize) -> Option<u16> {
+    data.get(at..at + 2).map(|b| u16::from_le_bytes([b[0], b[1]]))
+}
+
+fn read_offsets(data: &[u8], i: usize) -> Option<Ed25519Offsets> {
+    let base = ED25519_HEADER_LEN + i * ED25519_OFFSETS_LEN;
+    Some(Ed25519Offsets {
+        signature_offset: read_u16(data, base)?,
+        signature_instruction_index: read_u16(data, base + 2)?,
+        public_key_offset: read_u16(data, base + 4)?,
+        public_key_instruction_index: read_u16(data, base + 6)?,
+        message_data_offset: read_u16(data, base + 8)?,
+        message_data_size: read_u16(data, base + 10)?,
+        message_instruction_index: read_u16(data, base + 12)?,
+    })
+}
+
+/// Collects the (pubkey, message) pairs verified by a single Ed25519Program instruction.
+///
+/// Only self-contained entries are accepted: signature, pubkey and message must all live
+/// in the Ed25519 instruction data. Entries referencing other instructions are rejected so
+/// the data we inspect is exactly the data the runtime verified.
+fn parse_ed25519_entries(data: &[u8]) -> Result<Vec<(Pubkey, Vec<u8>)>> {
+    if data.len() < ED25519_HEADER_LEN {
+        return err!(M0OracleError::InvalidInstructionsSysvar);
+    }
+    let count = data[0] as usize;
+
+    let mut out = Vec::with_capacity(count);
+    for i in 0..count {
+        let o = read_offsets(data, i).ok_or(M0OracleError::InvalidInstructionsSysvar)?;
+
+        if o.signature_instruction_index != ED25519_SELF_INDEX
+            || o.public_key_instruction_index != ED25519_SELF_INDEX
+            || o.message_instruction_index != ED25519_SELF_INDEX
+        {
+            return err!(M0OracleError::InvalidInstructionsSysvar);
+        }
+
+        let sig_start = o.signature_offset as usize;
+        if data.get(sig_start..sig_start + ED25519_SIGNATURE_LEN).is_none() {
+            return err!(M0OracleError::InvalidInstructionsSysvar);
+        }
+
+        let pk_start = o.public_key_offset as usize;
+        let pk_bytes = data
+            .get(pk_start..pk_start + ED25519_PUBKEY_LEN)
+            .ok_or(M0OracleError::InvalidInstructionsSysvar)?;
+
+        let msg_start = o.message_data_offset as usize;
+        let msg = data
+            .get(msg_start..msg_start + o.message_data_size as usize)
+            .ok_or(M0OracleError::InvalidInstructionsSysvar)?;
+
+        let pubkey = Pubkey::try_from(pk_bytes).map_err(|_| M0OracleError::InvalidInstructionsSysvar)?;
+        out.push((pubkey, msg.to_vec()));
+    }
+    Ok(out)
+}
+
+/// Requires at least `threshold` distinct signer set members to have signed `message_hash`
+/// via Ed25519Program instructions in the current transaction.
+pub fn verify_threshold_signatures(
+    instructions_sysvar: &AccountInfo,
+    message_hash: &[u8; 32],
+    signer_pubkeys: &[Pubkey],
+    threshold: u16,
+) -> Result<()> {
     if signer_pubkeys.is_empty() || threshold == 0 || threshold as usize > signer_pubkeys.len() {
         return err!(M0OracleError::SignatureVerificationFailed);
     }
-    // Placeholder: treat presence of any signer set as "verified" in dev.
+
+    let ix_count = {
+        let data = instructions_sysvar.try_borrow_data()?;
+        read_u16(&data, 0).ok_or(M0OracleError::InvalidInstructionsSysvar)? as usize
+    };
+
+    let mut seen: Vec<Pubkey> = Vec::with_capacity(threshold as usize);
+    for idx in 0..ix_count {
+        let ix = load_instruction_at_checked(idx, instructions_sysvar)
+            .map_err(|_| M0OracleError::InvalidInstructionsSysvar)?;
+        if ix.program_id != ed25519_program::ID {
+            continue;
+        }
+
+        for (pubkey, msg) in parse_ed25519_entries(&ix.data)? {
+            if msg.as_slice() != message_hash.as_slice() {
+                continue;
+            }
+            if !signer_pubkeys.contains(&pubkey) || seen.contains(&pubkey) {
+                continue;
+            }
+            seen.push(pubkey);
+        }
+    }
+
+    if seen.len() < threshold as usize {
+        return err!(M0OracleError::SignatureVerificationFailed);
+    }
     Ok(())
 }
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::Pubkey;
+use m0_oracle::constants::PROB_SCALE;
+use m0_oracle::state::prediction::PredictedOutcome;
+use m0_oracle::state::submission::PublisherSubmission;
+use m0_oracle::utils::aggregate::{aggregate_outcomes, rescale_to_prob_scale};
+
+fn outcome(i: u8, p: u64) -> PredictedOutcome {
+    PredictedOutcome {
+        outcome_index: i,
+        p_scaled: p,
+        ci_low_scaled: p.saturating_sub(PROB_SCALE / 100),
+        ci_high_scaled: (p + PROB_SCALE / 100).min(PROB_SCALE),
+        ci_level_bps: 9_500,
+        quality_flags: 0,
+    }
+}
+
+fn submission(probs: &[u64]) -> PublisherSubmission {
+    PublisherSubmission {
+        epoch: Pubkey::default(),
+        publisher: Pubkey::new_unique(),
+        weight: 1,
+        signer_set_id: 1,
+        bundle_hash: [0u8; 32],
+        sequence: 1,
+        observed_at_ms: 0,
+        risk_score: 0,
+        quality_flags: 0,
+        revealed_at_slot: 0,
+        outcomes: probs.iter().enumerate().map(|(i, p)| outcome(i as u8, *p)).collect(),
+        bump: 0,
+    }
+}
+
+#[test]
+fn aggregate_sums_to_prob_scale() {
+    let tenth = PROB_SCALE / 10;
+    // Every per-outcome median is 0.3, so the raw medians sum to 0.9.
+    let subs = vec![
+        submission(&[6 * tenth, 3 * tenth, tenth]),
+        submission(&[tenth, 6 * tenth, 3 * tenth]),
+        submission(&[3 * tenth, tenth, 6 * tenth]),
+    ];
+
+    let out = aggregate_outcomes(&subs, 3);
+    assert_eq!(out.iter().map(|o| o.p_scaled).sum::<u64>(), PROB_SCALE);
+    // 1e9 / 3: the leftover unit goes to the lowest index.
+    assert_eq!(out.iter().map(|o| o.p_scaled).collect::<Vec<_>>(), vec![333_333_334, 333_333_333, 333_333_333]);
+    for o in &out {
+        assert!(o.ci_low_scaled <= o.p_scaled && o.p_scaled <= o.ci_high_scaled);
+        assert!(o.ci_high_scaled <= PROB_SCALE);
+    }
+}
+
+#[test]
+fn rescale_is_exact_and_deterministic() {
+    assert_eq!(rescale_to_prob_scale(&[1, 1]), vec![PROB_SCALE / 2, PROB_SCALE / 2]);
+    assert_eq!(rescale_to_prob_scale(&[0, 0, 0]).iter().sum::<u64>(), PROB_SCALE);
+    assert_eq!(rescale_to_prob_scale(&[2, 1]), vec![666_666_667, 333_333_333]);
+    assert!(rescale_to_prob_scale(&[]).is_empty());
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::{AccountMeta, Pubkey};
+use anchor_lang::solana_program::address_lookup_table::AddressLookupTableAccount;
+use anchor_lang::solana_program::hash::Hash;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::message::{v0, VersionedMessage};
+use anchor_lang::solana_program::{ed25519_program, system_program, sysvar};
+use anchor_lang::AnchorSerialize;
+use m0_oracle::constants::{BATCH_REVEAL_ACCOUNTS, MAX_BATCH_MARKETS, PROB_SCALE};
+use m0_oracle::state::reveal::{BundleReveal, MarketReveal, OutcomePoint};
+
+const PACKET_DATA_SIZE: usize = 1232;
+const ACCOUNT_LOCK_LIMIT: usize = 64;
+
+fn bundle(markets: usize) -> Vec<u8> {
+    let outcome = |id: &str| OutcomePoint {
+        outcome_id: id.to_string(),
+        p_scaled: PROB_SCALE / 2,
+        ci_low_scaled: PROB_SCALE / 2,
+        ci_high_scaled: PROB_SCALE / 2,
+        ci_level_bps: 9_500,
+        quality_flags: 0,
+    };
+    BundleReveal {
+        schema_version: 1,
+        signer_set_id: 1,
+        publish_epoch_id: 1,
+        created_at_ms: 0,
+        bundle_id: [0u8; 16],
+        markets: (0..markets)
+            .map(|i| MarketReveal {
+                market_id: format!("NBA_LAL_BOS_{i:02}"),
+                epoch_id: 1,
+                tick_index: 0,
+                sequence: 1,
+                observed_at_ms: 0,
+                risk_score: 0,
+                quality_flags: 0,
+                outcomes: vec![outcome("HOME"), outcome("AWAY")],
+            })
+            .collect(),
+    }
+    .try_to_vec()
+    .unwrap()
+}
+
+// `signatures` signatures over a 32-byte message (solana_sdk::ed25519_instruction layout).
+fn ed25519_ix(signatures: usize) -> Instruction {
+    Instruction::new_with_bytes(ed25519_program::ID, &vec![0u8; 2 + signatures * (14 + 32 + 64 + 32)], vec![])
+}
+
+// reveal_batch transaction with every non-signer, non-program account in a lookup table.
+fn reveal_batch_tx_size(markets: usize, signatures: usize) -> (usize, usize) {
+    let revealer = Pubkey::new_unique();
+    let mut accounts = vec![
+        AccountMeta::new(revealer, true),
+        AccountMeta::new_readonly(Pubkey::new_unique(), false), // config
+        AccountMeta::new_readonly(m0_oracle::ID, false),        // role: None
+        AccountMeta::new_readonly(Pubkey::new_unique(), false), // signer_set
+        AccountMeta::new_readonly(sysvar::instructions::ID, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+    for _ in 0..markets * BATCH_REVEAL_ACCOUNTS {
+        accounts.push(AccountMeta::new(Pubkey::new_unique(), false));
+    }
+    let table = AddressLookupTableAccount {
+        key: Pubkey::new_unique(),
+        addresses: accounts[1..].iter().map(|a| a.pubkey).filter(|k| *k != m0_oracle::ID).collect(),
+    };
+
+    let bundle_bytes = bundle(markets);
+    let mut data = vec![0u8; 8 + 32]; // discriminator, salt
+    data.extend((bundle_bytes.len() as u32).to_le_bytes());
+    data.extend(bundle_bytes);
+    let ix = Instruction { program_id: m0_oracle::ID, accounts, data };
+
+    let msg = v0::Message::try_compile(&revealer, &[ed25519_ix(signatures), ix], &[table], Hash::default()).unwrap();
+    let locked = msg.account_keys.len() + msg.address_table_lookups.iter().map(|l| l.writable_indexes.len() + l.readonly_indexes.len()).sum::<usize>();
+    let size = 1 + 64 + VersionedMessage::V0(msg).serialize().len(); // signature count + fee payer signature
+    (size, locked)
+}
+
+// A two-outcome bundle at the cap, signed 2-of-n, in one transaction.
+#[test]
+fn max_batch_fits_one_transaction() {
+    let (size, locked) = reveal_batch_tx_size(MAX_BATCH_MARKETS, 2);
+    assert!(locked <= ACCOUNT_LOCK_LIMIT, "{locked} accounts");
+    assert!(size <= PACKET_DATA_SIZE, "{size} bytes");
+}
@@ -0,0 +1,12 @@
+use m0_oracle::utils::hashing::hash_merkle_commitment;
+
+// Same vector as core-engine m0-bundle tests/merkle.rs (merkle_commitment).
+#[test]
+fn merkle_commitment_matches_engine_vector() {
+    let expected: [u8; 32] = [
+        0x71, 0x63, 0x12, 0x45, 0x95, 0x4a, 0xe6, 0x83, 0x63, 0x59, 0x4b, 0x5d, 0x3c, 0x49, 0xa3, 0xbf,
+        0xb4, 0x1f, 0xfb, 0xc7, 0x00, 0x98, 0xf4, 0xd4, 0x87, 0x2b, 0x03, 0x59, 0xd2, 0x0a, 0x86, 0x78,
+    ];
+    assert_eq!(hash_merkle_commitment(&[0x11; 32], 3), expected);
+    assert_ne!(hash_merkle_commitment(&[0x11; 32], 3), hash_merkle_commitment(&[0x11; 32], 4));
+}
@@ -9,4 +9,12 @@ pub enum M0RegistryError {
     AlreadyInitialized,
     #[msg("Invalid parameter")]
     InvalidParameter,
+    #[msg("No pending authority transfer")]
+    NoPendingAuthority,
+    #[msg("Invalid market status transition")]
+    InvalidStatusTransition,
+    #[msg("Resolved or archived markets can't be edited")]
+    MarketNotEditable,
+    #[msg("Domain index is full")]
+    DomainIndexFull,
 }
@@ -1,5 +1,6 @@
 
 use anchor_lang::prelude::*;
+use crate::state::metadata::{MarketDetails, MarketMetadata, MarketStatus};
 
 #[event]
 pub struct RegistryInitialized {
@@ -7,13 +8,120 @@ pub struct RegistryInitialized {
     pub created_at_slot: u64,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct StringChange {
+    pub old: String,
+    pub new: String,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct U32Change {
+    pub old: u32,
+    pub new: u32,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct U64Change {
+    pub old: u64,
+    pub new: u64,
+}
+
+// Field-level changes applied by one upsert; empty for a newly created entry.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct MetadataDiff {
+    pub domain: Option<StringChange>,
+    pub cadence_ms: Option<U32Change>,
+    pub tier_policy: Option<StringChange>,
+    pub outcomes_added: Vec<String>,
+    pub outcomes_removed: Vec<String>,
+    pub outcomes_reordered: bool,
+    pub description_changed: bool, // new text is in the account; not repeated here
+    pub tags_added: Vec<String>,
+    pub tags_removed: Vec<String>,
+    pub resolution_source: Option<StringChange>,
+    pub close_time_ms: Option<U64Change>,
+}
+
+impl MetadataDiff {
+    pub fn between(old: &MarketMetadata, domain: &str, cadence_ms: u32, tier_policy: &str, outcomes: &[String], details: &MarketDetails) -> Self {
+        let changed = |a: &str, b: &str| (a != b).then(|| StringChange { old: a.to_string(), new: b.to_string() });
+        let outcomes_added: Vec<String> = outcomes.iter().filter(|o| !old.outcomes.contains(o)).cloned().collect();
+        let outcomes_removed: Vec<String> = old.outcomes.iter().filter(|o| !outcomes.contains(o)).cloned().collect();
+        let outcomes_reordered = outcomes_added.is_empty() && outcomes_removed.is_empty() && old.outcomes.as_slice() != outcomes;
+        MetadataDiff {
+            domain: changed(&old.domain, domain),
+            cadence_ms: (old.cadence_ms != cadence_ms).then_some(U32Change { old: old.cadence_ms, new: cadence_ms }),
+            tier_policy: changed(&old.tier_policy, tier_policy),
+            outcomes_added,
+            outcomes_removed,
+            outcomes_reordered,
+            description_changed: old.details.description != details.description,
+            tags_added: details.tags.iter().filter(|t| !old.details.tags.contains(t)).cloned().collect(),
+            tags_removed: old.details.tags.iter().filter(|t| !details.tags.contains(t)).cloned().collect(),
+            resolution_source: changed(&old.details.resolution_source, &details.resolution_source),
+            close_time_ms: (old.details.close_time_ms != details.close_time_ms).then_some(U64Change { old: old.details.close_time_ms, new: details.close_time_ms }),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.domain.is_none()
+            && self.cadence_ms.is_none()
+            && self.tier_policy.is_none()
+            && self.outcomes_added.is_empty()
+            && self.outcomes_removed.is_empty()
+            && !self.outcomes_reordered
+            && !self.description_changed
+            && self.tags_added.is_empty()
+            && self.tags_removed.is_empty()
+            && self.resolution_source.is_none()
+            && self.close_time_ms.is_none()
+    }
+}
+
 #[event]
 pub struct MarketUpserted {
     pub market_id: String,
-    pub active: bool,
+    pub domain: String,
+    pub editor: Pubkey, // registry authority or the domain curator
+    pub created: bool,
+    pub status: MarketStatus,
+    pub diff: MetadataDiff,
+    pub updated_at_slot: u64,
+}
+
+#[event]
+pub struct CuratorSet {
+    pub domain: String,
+    pub old_curator: Option<Pubkey>,
+    pub new_curator: Option<Pubkey>,
+    pub set_at_slot: u64,
+}
+
+#[event]
+pub struct MarketStatusChanged {
+    pub market_id: String,
+    pub old_status: MarketStatus,
+    pub new_status: MarketStatus,
+    pub changed_at_slot: u64,
+}
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub proposed_at_slot: u64,
+}
+
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub authority: Pubkey,
+    pub cancelled_pending_authority: Pubkey,
+    pub cancelled_at_slot: u64,
 }
 
 #[event]
-pub struct AuthoritySet {
+pub struct AuthorityTransferred {
+    pub previous_authority: Pubkey,
     pub new_authority: Pubkey,
+    pub accepted_at_slot: u64,
 }
@@ -0,0 +1,37 @@
+
+use anchor_lang::prelude::*;
+use crate::events::*;
+use crate::state::registry::{Registry, REGISTRY_SEED};
+use crate::error::M0RegistryError;
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [REGISTRY_SEED],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, Registry>,
+}
+
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let r = &mut ctx.accounts.registry;
+    let signer = ctx.accounts.new_authority.key();
+    match r.pending_authority {
+        None => return err!(M0RegistryError::NoPendingAuthority),
+        Some(p) if p != signer => return err!(M0RegistryError::Unauthorized),
+        Some(_) => {}
+    }
+
+    let previous = r.authority;
+    r.authority = signer;
+    r.pending_authority = None;
+    emit!(AuthorityTransferred {
+        previous_authority: previous,
+        new_authority: signer,
+        accepted_at_slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
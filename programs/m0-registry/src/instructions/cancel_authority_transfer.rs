@@ -5,7 +5,7 @@ use crate::state::registry::{Registry, REGISTRY_SEED};
 use crate::error::M0RegistryError;
 
 #[derive(Accounts)]
-pub struct SetAuthority<'info> {
+pub struct CancelAuthorityTransfer<'info> {
     pub authority: Signer<'info>,
 
     #[account(
@@ -17,9 +17,13 @@ pub struct SetAuthority<'info> {
     pub registry: Account<'info, Registry>,
 }
 
-pub fn handler(ctx: Context<SetAuthority>, new_authority: Pubkey) -> Result<()> {
+pub fn handler(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
     let r = &mut ctx.accounts.registry;
-    r.authority = new_authority;
-    emit!(AuthoritySet { new_authority });
+    let pending = r.pending_authority.take().ok_or(M0RegistryError::NoPendingAuthority)?;
+    emit!(AuthorityTransferCancelled {
+        authority: r.authority,
+        cancelled_pending_authority: pending,
+        cancelled_at_slot: Clock::get()?.slot,
+    });
     Ok(())
 }
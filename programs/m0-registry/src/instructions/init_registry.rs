@@ -28,6 +28,7 @@ pub fn handler(ctx: Context<InitRegistry>) -> Result<()> {
     }
     r.initialized = true;
     r.authority = ctx.accounts.authority.key();
+    r.pending_authority = None;
     r.market_count = 0;
     r.bump = *ctx.bumps.get("registry").unwrap();
 
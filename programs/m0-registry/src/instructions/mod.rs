@@ -1,4 +1,7 @@
 
+pub mod accept_authority;
+pub mod cancel_authority_transfer;
 pub mod init_registry;
-pub mod set_authority;
+pub mod propose_authority;
+pub mod set_curator;
 pub mod upsert_market;
@@ -0,0 +1,32 @@
+
+use anchor_lang::prelude::*;
+use crate::events::*;
+use crate::state::registry::{Registry, REGISTRY_SEED};
+use crate::error::M0RegistryError;
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [REGISTRY_SEED],
+        bump = registry.bump,
+        has_one = authority @ M0RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, Registry>,
+}
+
+pub fn handler(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    let r = &mut ctx.accounts.registry;
+    if new_authority == Pubkey::default() || new_authority == r.authority {
+        return err!(M0RegistryError::InvalidParameter);
+    }
+    r.pending_authority = Some(new_authority);
+    emit!(AuthorityTransferProposed {
+        authority: r.authority,
+        pending_authority: new_authority,
+        proposed_at_slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::error::M0RegistryError;
+use crate::events::*;
+use crate::state::domain::{DomainIndex, DOMAIN_SEED};
+use crate::state::metadata::validate_domain;
+use crate::state::registry::{Registry, REGISTRY_SEED};
+
+// Creates the domain's index on first use. The curator can upsert markets of this
+// domain without the registry authority.
+
+#[derive(Accounts)]
+#[instruction(domain: String)]
+pub struct SetCurator<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [REGISTRY_SEED],
+        bump = registry.bump,
+        has_one = authority @ M0RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = DomainIndex::len_with(0),
+        seeds = [DOMAIN_SEED, domain.as_bytes()],
+        bump
+    )]
+    pub domain_index: Account<'info, DomainIndex>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetCurator>, domain: String, curator: Option<Pubkey>) -> Result<()> {
+    if !validate_domain(&domain) || curator == Some(Pubkey::default()) {
+        return err!(M0RegistryError::InvalidParameter);
+    }
+
+    let d = &mut ctx.accounts.domain_index;
+    if d.domain.is_empty() {
+        d.domain = domain.clone();
+        d.markets = vec![];
+        d.bump = *ctx.bumps.get("domain_index").unwrap();
+    }
+    let old_curator = d.curator;
+    d.curator = curator;
+
+    emit!(CuratorSet {
+        domain,
+        old_curator,
+        new_curator: curator,
+        set_at_slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
@@ -1,37 +1,51 @@
-
 use anchor_lang::prelude::*;
 use crate::events::*;
-use crate::state::metadata::{MarketMetadata, MARKET_META_SEED};
+use crate::state::domain::{DomainIndex, DOMAIN_SEED, MAX_DOMAIN_MARKETS};
+use crate::state::metadata::*;
 use crate::state::registry::{Registry, REGISTRY_SEED};
 use crate::error::M0RegistryError;
+use crate::utils::accounts::{create_pda, grow};
 
-const MAX_OUTCOME_LEN: usize = 64;
+// Signed by the registry authority or the curator of the market's domain. Moving a
+// market to another domain needs edit rights on both and the old domain's index.
 
 #[derive(Accounts)]
-#[instruction(market_id: String)]
+#[instruction(market_id: String, domain: String)]
 pub struct UpsertMarket<'info> {
-    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub signer: Signer<'info>,
 
     #[account(
         mut,
         seeds = [REGISTRY_SEED],
-        bump = registry.bump,
-        has_one = authority @ M0RegistryError::Unauthorized
+        bump = registry.bump
     )]
     pub registry: Account<'info, Registry>,
 
     #[account(
-        init_if_needed,
-        payer = authority,
-        space = MarketMetadata::len_with(market_id.len().max(1), outcomes.len().max(1), MAX_OUTCOME_LEN),
+        mut,
+        seeds = [DOMAIN_SEED, domain.as_bytes()],
+        bump = domain_index.bump
+    )]
+    pub domain_index: Account<'info, DomainIndex>, // grown in the handler when a key is added
+
+    // Required when the market's domain changes.
+    #[account(mut)]
+    pub old_domain_index: Option<Account<'info, DomainIndex>>,
+
+    /// CHECK: MarketMetadata PDA; created or grown in the handler, then (de)serialized
+    /// with the discriminator check.
+    #[account(
+        mut,
         seeds = [MARKET_META_SEED, market_id.as_bytes()],
         bump
     )]
-    pub meta: Account<'info, MarketMetadata>,
+    pub meta: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<UpsertMarket>,
     market_id: String,
@@ -39,34 +53,133 @@ pub fn handler(
     cadence_ms: u32,
     tier_policy: String,
     outcomes: Vec<String>,
-    active: bool,
+    details: MarketDetails,
+    status: MarketStatus,
 ) -> Result<()> {
-    if market_id.is_empty() || market_id.len() > 64 {
+    if market_id.is_empty() || market_id.len() > MAX_MARKET_ID_LEN {
         return err!(M0RegistryError::InvalidParameter);
     }
-    if outcomes.is_empty() || outcomes.len() > 16 {
+    if !validate_domain(&domain) || !details.validate() || tier_policy.len() > MAX_TIER_POLICY_LEN || cadence_ms == 0 {
         return err!(M0RegistryError::InvalidParameter);
     }
+    if outcomes.is_empty() || outcomes.len() > MAX_OUTCOMES {
+        return err!(M0RegistryError::InvalidParameter);
+    }
+    for (i, o) in outcomes.iter().enumerate() {
+        if o.is_empty() || o.len() > MAX_OUTCOME_LEN || outcomes[..i].contains(o) {
+            return err!(M0RegistryError::InvalidParameter);
+        }
+    }
 
-    let m = &mut ctx.accounts.meta;
+    let signer = ctx.accounts.signer.key();
+    let registry_authority = ctx.accounts.registry.authority;
+    if !ctx.accounts.domain_index.can_edit(&registry_authority, &signer) {
+        return err!(M0RegistryError::Unauthorized);
+    }
+
+    let info = ctx.accounts.meta.to_account_info();
+    let authority = ctx.accounts.signer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let space = MarketMetadata::len_for(&market_id, &domain, &tier_policy, &outcomes, &details);
+    let bump = *ctx.bumps.get("meta").unwrap();
     let now = Clock::get()?.slot;
 
-    let is_new = m.created_at_slot == 0;
-    if is_new {
+    let created = info.owner != &crate::ID;
+    let (old, mut m) = if created {
+        if !matches!(status, MarketStatus::Draft | MarketStatus::Active) {
+            return err!(M0RegistryError::InvalidStatusTransition);
+        }
+        create_pda(&authority, &info, &system_program, space, &[MARKET_META_SEED, market_id.as_bytes(), &[bump]])?;
         ctx.accounts.registry.market_count = ctx.accounts.registry.market_count.saturating_add(1);
-        m.created_at_slot = now;
+        let m = MarketMetadata {
+            market_id: market_id.clone(),
+            domain: domain.clone(),
+            cadence_ms,
+            tier_policy: tier_policy.clone(),
+            outcomes: outcomes.clone(),
+            details: details.clone(),
+            status,
+            created_at_slot: now,
+            updated_at_slot: now,
+            bump,
+        };
+        (None, m)
+    } else {
+        let m = MarketMetadata::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        (Some(m.clone()), m)
+    };
+
+    let diff = match &old {
+        Some(o) => MetadataDiff::between(o, &domain, cadence_ms, &tier_policy, &outcomes, &details),
+        None => MetadataDiff::default(),
+    };
+    let old_status = old.as_ref().map(|o| o.status).unwrap_or(status);
+
+    if !old_status.can_transition_to(status) {
+        return err!(M0RegistryError::InvalidStatusTransition);
+    }
+    if !diff.is_empty() && !old_status.is_editable() {
+        return err!(M0RegistryError::MarketNotEditable);
     }
 
-    m.market_id = market_id.clone();
-    m.domain = domain;
+    // Keep the domain indexes in sync. Archived markets leave the index, so the cap
+    // bounds the live markets of a domain; Archived is terminal, so they never return.
+    let key = info.key();
+    let moved = old.as_ref().map(|o| o.domain != domain).unwrap_or(true);
+    let listed = old.as_ref().map(|o| o.status != MarketStatus::Archived).unwrap_or(false);
+    let list = status != MarketStatus::Archived;
+    if listed && moved {
+        let od = ctx.accounts.old_domain_index.as_mut().ok_or(M0RegistryError::InvalidParameter)?;
+        if Some(&od.domain) != old.as_ref().map(|o| &o.domain) {
+            return err!(M0RegistryError::InvalidParameter);
+        }
+        if !od.can_edit(&registry_authority, &signer) {
+            return err!(M0RegistryError::Unauthorized);
+        }
+        od.markets.retain(|k| *k != key);
+    } else if listed && !list {
+        ctx.accounts.domain_index.markets.retain(|k| *k != key);
+    }
+    if list && (moved || !listed) {
+        let d = &mut ctx.accounts.domain_index;
+        if d.markets.len() >= MAX_DOMAIN_MARKETS {
+            return err!(M0RegistryError::DomainIndexFull);
+        }
+        // Exact growth; a doubling push would reallocate twice the index on the heap.
+        d.markets.reserve_exact(1);
+        d.markets.push(key);
+        // Removals leave slack, so this only reallocs past the largest size so far.
+        grow(&authority, &d.to_account_info(), &system_program, DomainIndex::len_with(d.markets.len()))?;
+    }
+
+    m.domain = domain.clone();
     m.cadence_ms = cadence_ms;
     m.tier_policy = tier_policy;
     m.outcomes = outcomes;
-    m.active = active;
+    m.details = details;
+    m.status = status;
     m.updated_at_slot = now;
-    m.bump = *ctx.bumps.get("meta").unwrap();
 
-    emit!(MarketUpserted { market_id, active });
+    grow(&authority, &info, &system_program, space)?;
+    m.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    if old_status != status {
+        emit!(MarketStatusChanged {
+            market_id: market_id.clone(),
+            old_status,
+            new_status: status,
+            changed_at_slot: now,
+        });
+    }
+    emit!(MarketUpserted {
+        market_id,
+        domain,
+        editor: signer,
+        created,
+        status,
+        diff,
+        updated_at_slot: now,
+    });
 
     Ok(())
 }
@@ -5,6 +5,7 @@ pub mod error;
 pub mod events;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 use instructions::*;
 
@@ -18,6 +19,7 @@ pub mod m0_registry {
         init_registry::handler(ctx)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn upsert_market(
         ctx: Context<upsert_market::UpsertMarket>,
         market_id: String,
@@ -25,12 +27,25 @@ pub mod m0_registry {
         cadence_ms: u32,
         tier_policy: String,
         outcomes: Vec<String>,
-        active: bool,
+        details: state::metadata::MarketDetails,
+        status: state::metadata::MarketStatus,
     ) -> Result<()> {
-        upsert_market::handler(ctx, market_id, domain, cadence_ms, tier_policy, outcomes, active)
+        upsert_market::handler(ctx, market_id, domain, cadence_ms, tier_policy, outcomes, details, status)
     }
 
-    pub fn set_authority(ctx: Context<set_authority::SetAuthority>, new_authority: Pubkey) -> Result<()> {
-        set_authority::handler(ctx, new_authority)
+    pub fn set_curator(ctx: Context<set_curator::SetCurator>, domain: String, curator: Option<Pubkey>) -> Result<()> {
+        set_curator::handler(ctx, domain, curator)
+    }
+
+    pub fn propose_authority(ctx: Context<propose_authority::ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        propose_authority::handler(ctx, new_authority)
+    }
+
+    pub fn accept_authority(ctx: Context<accept_authority::AcceptAuthority>) -> Result<()> {
+        accept_authority::handler(ctx)
+    }
+
+    pub fn cancel_authority_transfer(ctx: Context<cancel_authority_transfer::CancelAuthorityTransfer>) -> Result<()> {
+        cancel_authority_transfer::handler(ctx)
     }
 }
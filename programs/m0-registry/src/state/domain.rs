@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+pub const DOMAIN_SEED: &[u8] = b"domain";
+// Loading the index copies `markets` onto the 32 KiB bump heap, which never frees, and
+// upsert_market may load two plus grow one by a key. At 128 keys that is ~12 KiB (see
+// tests/domain_index.rs), leaving room for the metadata and instruction args.
+pub const MAX_DOMAIN_MARKETS: usize = 128;
+
+// Per-domain curator and market index, [DOMAIN_SEED, domain]. Created by set_curator;
+// upsert_market keeps `markets` in sync (archived markets are removed) and grows the
+// account when an entry is added.
+#[account]
+pub struct DomainIndex {
+    pub domain: String,
+    pub curator: Option<Pubkey>, // may upsert markets of this domain; None = authority only
+    pub markets: Vec<Pubkey>,    // MarketMetadata PDAs, in creation order
+    pub bump: u8,
+}
+
+impl DomainIndex {
+    pub fn len_with(market_count: usize) -> usize {
+        // domain: 4 + MAX_DOMAIN_LEN
+        // curator: 1 + 32
+        // markets: 4 + market_count * 32
+        8 + 4 + crate::state::metadata::MAX_DOMAIN_LEN + (1 + 32) + 4 + market_count * 32 + 1
+    }
+
+    pub fn can_edit(&self, registry_authority: &Pubkey, signer: &Pubkey) -> bool {
+        signer == registry_authority || self.curator.as_ref() == Some(signer)
+    }
+}
@@ -1,7 +1,69 @@
-
 use anchor_lang::prelude::*;
 
 pub const MARKET_META_SEED: &[u8] = b"market_meta";
+pub const MAX_MARKET_ID_LEN: usize = 64;
+pub const MAX_DOMAIN_LEN: usize = 16;
+pub const MAX_TIER_POLICY_LEN: usize = 16;
+pub const MAX_OUTCOMES: usize = 16;
+pub const MAX_OUTCOME_LEN: usize = 64;
+pub const MAX_DESCRIPTION_LEN: usize = 256;
+pub const MAX_TAGS: usize = 8;
+pub const MAX_TAG_LEN: usize = 32;
+pub const MAX_RESOLUTION_SOURCE_LEN: usize = 128;
+
+// Lowercase ascii letters, digits, '_' and '-'; also the DomainIndex seed.
+pub fn validate_domain(domain: &str) -> bool {
+    !domain.is_empty() && domain.len() <= MAX_DOMAIN_LEN && domain.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_' || b == b'-')
+}
+
+// Descriptive fields from config/markets/*.toml.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Eq)]
+pub struct MarketDetails {
+    pub description: String,
+    pub tags: Vec<String>,
+    pub resolution_source: String, // URL or name of the source used to resolve the market
+    pub close_time_ms: u64,        // unix ms after which the outcome is determined; 0 = open-ended
+}
+
+impl MarketDetails {
+    pub fn validate(&self) -> bool {
+        self.description.len() <= MAX_DESCRIPTION_LEN
+            && self.resolution_source.len() <= MAX_RESOLUTION_SOURCE_LEN
+            && self.tags.len() <= MAX_TAGS
+            && self.tags.iter().enumerate().all(|(i, t)| !t.is_empty() && t.len() <= MAX_TAG_LEN && !self.tags[..i].contains(t))
+    }
+
+    pub fn space(&self) -> usize {
+        4 + self.description.len() + 4 + self.tags.iter().map(|t| 4 + t.len()).sum::<usize>() + 4 + self.resolution_source.len() + 8
+    }
+}
+
+// Draft -> Active <-> Suspended; Active | Suspended -> Resolved -> Archived.
+// Draft and Suspended can also be archived directly. Archived is terminal.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MarketStatus {
+    Draft,
+    Active,
+    Suspended,
+    Resolved,
+    Archived,
+}
+
+impl MarketStatus {
+    pub fn can_transition_to(self, to: MarketStatus) -> bool {
+        use MarketStatus::*;
+        self == to
+            || matches!(
+                (self, to),
+                (Draft, Active) | (Draft, Archived) | (Active, Suspended) | (Active, Resolved) | (Suspended, Active) | (Suspended, Resolved) | (Suspended, Archived) | (Resolved, Archived)
+            )
+    }
+
+    // Resolved and archived entries are historical records; only the status can change.
+    pub fn is_editable(self) -> bool {
+        matches!(self, MarketStatus::Draft | MarketStatus::Active | MarketStatus::Suspended)
+    }
+}
 
 #[account]
 pub struct MarketMetadata {
@@ -10,21 +72,28 @@ pub struct MarketMetadata {
     pub cadence_ms: u32,
     pub tier_policy: String,
     pub outcomes: Vec<String>,
-    pub active: bool,
+    pub details: MarketDetails,
+    pub status: MarketStatus,
     pub created_at_slot: u64,
     pub updated_at_slot: u64,
     pub bump: u8,
 }
 
 impl MarketMetadata {
-    pub fn len_with(market_id_len: usize, outcomes_len: usize, max_outcome_len: usize) -> usize {
-        8 + 4 + market_id_len
-        + 4 + 16 // domain small string
+    // Exact size for the given contents; upsert_market grows the account to fit.
+    pub fn len_for(market_id: &str, domain: &str, tier_policy: &str, outcomes: &[String], details: &MarketDetails) -> usize {
+        8 + 4 + market_id.len()
+        + 4 + domain.len()
         + 4       // cadence_ms
-        + 4 + 16  // tier_policy small string
-        + 4 + outcomes_len * (4 + max_outcome_len)
-        + 1
+        + 4 + tier_policy.len()
+        + 4 + outcomes.iter().map(|o| 4 + o.len()).sum::<usize>()
+        + details.space()
+        + 1       // status
         + 8 + 8
         + 1
     }
+
+    pub fn is_active(&self) -> bool {
+        self.status == MarketStatus::Active
+    }
 }
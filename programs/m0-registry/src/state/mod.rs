@@ -1,3 +1,3 @@
-
+pub mod domain;
 pub mod metadata;
 pub mod registry;
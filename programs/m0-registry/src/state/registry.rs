@@ -7,10 +7,11 @@ pub const REGISTRY_SEED: &[u8] = b"registry";
 pub struct Registry {
     pub initialized: bool,
     pub authority: Pubkey,
+    pub pending_authority: Option<Pubkey>,
     pub market_count: u64,
     pub bump: u8,
 }
 
 impl Registry {
-    pub const LEN: usize = 8 + 1 + 32 + 8 + 1;
+    pub const LEN: usize = 8 + 1 + 32 + (1 + 32) + 8 + 1;
 }
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::error::M0RegistryError;
+
+// upsert_market creates or resizes MarketMetadata by hand: Anchor can't combine
+// init_if_needed with realloc on one account. DomainIndex is grown here too, and only
+// when upsert_market adds a key.
+
+/// Creates a rent-exempt program-owned PDA of `space` bytes, like `init`.
+pub fn create_pda<'info>(
+    payer: &AccountInfo<'info>,
+    target: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    if target.owner != &system_program::ID || !target.data_is_empty() {
+        return err!(M0RegistryError::InvalidParameter);
+    }
+
+    let rent = Rent::get()?.minimum_balance(space);
+    let current = target.lamports();
+    let signer = &[signer_seeds];
+
+    if current == 0 {
+        let accounts = system_program::CreateAccount { from: payer.clone(), to: target.clone() };
+        system_program::create_account(CpiContext::new_with_signer(system_program.clone(), accounts, signer), rent, space as u64, &crate::ID)?;
+        return Ok(());
+    }
+
+    if rent > current {
+        let accounts = system_program::Transfer { from: payer.clone(), to: target.clone() };
+        system_program::transfer(CpiContext::new(system_program.clone(), accounts), rent - current)?;
+    }
+    let accounts = system_program::Allocate { account_to_allocate: target.clone() };
+    system_program::allocate(CpiContext::new_with_signer(system_program.clone(), accounts, signer), space as u64)?;
+    let accounts = system_program::Assign { account_to_assign: target.clone() };
+    system_program::assign(CpiContext::new_with_signer(system_program.clone(), accounts, signer), &crate::ID)?;
+    Ok(())
+}
+
+/// Grows a program-owned account to `space` bytes (never shrinks) and tops up rent.
+pub fn grow<'info>(payer: &AccountInfo<'info>, target: &AccountInfo<'info>, system_program: &AccountInfo<'info>, space: usize) -> Result<()> {
+    if space <= target.data_len() {
+        return Ok(());
+    }
+    let rent = Rent::get()?.minimum_balance(space);
+    let current = target.lamports();
+    if rent > current {
+        let accounts = system_program::Transfer { from: payer.clone(), to: target.clone() };
+        system_program::transfer(CpiContext::new(system_program.clone(), accounts), rent - current)?;
+    }
+    target.realloc(space, false)?;
+    Ok(())
+}
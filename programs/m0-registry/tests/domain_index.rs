@@ -0,0 +1,66 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AccountDeserialize, AccountSerialize};
+use m0_registry::state::domain::{DomainIndex, MAX_DOMAIN_MARKETS};
+
+// Sums every allocation on the current thread, like the program's bump heap, which
+// never frees. Reallocs go through `alloc`, so growth is counted at the new size.
+struct Counting;
+
+thread_local! {
+    static ALLOCATED: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATED.try_with(|a| a.set(a.get() + layout.size()));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn heap_used<R>(f: impl FnOnce() -> R) -> (usize, R) {
+    let before = ALLOCATED.with(Cell::get);
+    let r = f();
+    (ALLOCATED.with(Cell::get) - before, r)
+}
+
+fn account_data(market_count: usize) -> Vec<u8> {
+    let index = DomainIndex {
+        domain: "sports".to_string(),
+        curator: Some(Pubkey::new_unique()),
+        markets: (0..market_count).map(|_| Pubkey::new_unique()).collect(),
+        bump: 255,
+    };
+    let mut data = Vec::with_capacity(DomainIndex::len_with(market_count));
+    index.try_serialize(&mut data).unwrap();
+    assert!(data.len() <= DomainIndex::len_with(market_count));
+    data
+}
+
+#[test]
+fn full_index_fits_heap_budget() {
+    // upsert_market moving a market loads the old index at the cap and adds to a new one
+    // just below it. Keep both within half of the 32 KiB heap.
+    let old = account_data(MAX_DOMAIN_MARKETS);
+    let new = account_data(MAX_DOMAIN_MARKETS - 1);
+    let key = Pubkey::new_unique();
+
+    let (used, _) = heap_used(|| {
+        let mut od = DomainIndex::try_deserialize(&mut &old[..]).unwrap();
+        let mut d = DomainIndex::try_deserialize(&mut &new[..]).unwrap();
+        od.markets.retain(|k| *k != key);
+        d.markets.reserve_exact(1);
+        d.markets.push(key);
+        (od, d)
+    });
+    assert!(used <= 16 * 1024, "domain indexes used {used} bytes of heap");
+}
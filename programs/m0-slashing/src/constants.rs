@@ -0,0 +1,5 @@
+pub const SLASHING_CONFIG_SEED: &[u8] = b"slashing_config";
+pub const BOND_VAULT_SEED: &[u8] = b"bond_vault";
+pub const SLASH_CASE_SEED: &[u8] = b"slash_case";
+
+pub const BPS_DENOMINATOR: u64 = 10_000;
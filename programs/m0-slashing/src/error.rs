@@ -0,0 +1,53 @@
+
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum M0SlashingError {
+    #[msg("Unauthorized")]
+    Unauthorized,
+
+    #[msg("Invalid parameter")]
+    InvalidParameter,
+
+    #[msg("No pending authority transfer")]
+    NoPendingAuthority,
+
+    #[msg("Slashing disabled")]
+    SlashingDisabled,
+
+    #[msg("Insufficient bond")]
+    InsufficientBond,
+
+    #[msg("Unbonding period not elapsed")]
+    UnbondingNotElapsed,
+
+    #[msg("No unbonding request")]
+    NoUnbondingRequest,
+
+    #[msg("Bond has open slash cases")]
+    BondHasOpenCases,
+
+    #[msg("Offender is not a member of the signer set")]
+    NotSignerSetMember,
+
+    #[msg("Conflicting bundle hashes are identical")]
+    NotConflicting,
+
+    #[msg("Commit was not expired by the oracle")]
+    CommitNotExpired,
+
+    #[msg("Slash case not pending")]
+    CaseNotPending,
+
+    #[msg("Dispute window still open")]
+    DisputeWindowOpen,
+
+    #[msg("Dispute window closed")]
+    DisputeWindowClosed,
+
+    #[msg("Math overflow")]
+    MathOverflow,
+
+    #[msg("Evidence epoch is not the market's epoch PDA")]
+    EvidenceMismatch,
+}
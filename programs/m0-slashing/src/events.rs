@@ -0,0 +1,89 @@
+
+use anchor_lang::prelude::*;
+use crate::state::bond::OperatorKind;
+use crate::state::case::{Offense, Severity};
+use crate::state::config::SlashingParams;
+
+#[event]
+pub struct SlashingParamsSet {
+    pub authority: Pubkey,
+    pub params: SlashingParams,
+}
+
+#[event]
+pub struct BondDeposited {
+    pub bond: Pubkey,
+    pub operator: Pubkey,
+    pub kind: OperatorKind,
+    pub amount: u64,
+    pub total: u64,
+}
+
+#[event]
+pub struct UnbondRequested {
+    pub bond: Pubkey,
+    pub operator: Pubkey,
+    pub amount: u64,
+    pub withdrawable_at_slot: u64,
+}
+
+#[event]
+pub struct BondWithdrawn {
+    pub bond: Pubkey,
+    pub operator: Pubkey,
+    pub amount: u64,
+    pub remaining: u64,
+}
+
+#[event]
+pub struct SlashCaseOpened {
+    pub case: Pubkey,
+    pub bond: Pubkey,
+    pub offender: Pubkey,
+    pub offense: Offense,
+    pub severity: Severity,
+    pub penalty_bps: u16,
+    pub evidence_hash: [u8; 32],
+    pub reporter: Pubkey,
+    pub executable_at_slot: u64,
+}
+
+#[event]
+pub struct SlashCaseCancelled {
+    pub case: Pubkey,
+    pub offender: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub cancelled_at_slot: u64,
+}
+
+#[event]
+pub struct SlashExecuted {
+    pub case: Pubkey,
+    pub bond: Pubkey,
+    pub offender: Pubkey,
+    pub amount_slashed: u64,
+    pub reporter_reward: u64,
+    pub routed_to_fee_vault: u64,
+    pub executed_at_slot: u64,
+}
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub proposed_at_slot: u64,
+}
+
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub authority: Pubkey,
+    pub cancelled_pending_authority: Pubkey,
+    pub cancelled_at_slot: u64,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub accepted_at_slot: u64,
+}
@@ -0,0 +1,38 @@
+
+use anchor_lang::prelude::*;
+use crate::error::M0SlashingError;
+use crate::events::*;
+use crate::constants::*;
+use crate::state::config::SlashingConfig;
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SLASHING_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, SlashingConfig>,
+}
+
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let r = &mut ctx.accounts.config;
+    let signer = ctx.accounts.new_authority.key();
+    match r.pending_authority {
+        None => return err!(M0SlashingError::NoPendingAuthority),
+        Some(p) if p != signer => return err!(M0SlashingError::Unauthorized),
+        Some(_) => {}
+    }
+
+    let previous = r.authority;
+    r.authority = signer;
+    r.pending_authority = None;
+    emit!(AuthorityTransferred {
+        previous_authority: previous,
+        new_authority: signer,
+        accepted_at_slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
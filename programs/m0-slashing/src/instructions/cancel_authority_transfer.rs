@@ -0,0 +1,30 @@
+
+use anchor_lang::prelude::*;
+use crate::error::M0SlashingError;
+use crate::events::*;
+use crate::constants::*;
+use crate::state::config::SlashingConfig;
+
+#[derive(Accounts)]
+pub struct CancelAuthorityTransfer<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SLASHING_CONFIG_SEED],
+        bump = config.bump,
+        has_one = authority @ M0SlashingError::Unauthorized
+    )]
+    pub config: Account<'info, SlashingConfig>,
+}
+
+pub fn handler(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+    let r = &mut ctx.accounts.config;
+    let pending = r.pending_authority.take().ok_or(M0SlashingError::NoPendingAuthority)?;
+    emit!(AuthorityTransferCancelled {
+        authority: r.authority,
+        cancelled_pending_authority: pending,
+        cancelled_at_slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
@@ -0,0 +1,66 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::M0SlashingError;
+use crate::events::*;
+use crate::state::bond::OperatorBond;
+use crate::state::case::{CaseState, SlashCase};
+use crate::state::config::SlashingConfig;
+
+// Authority veto during the dispute window. A cancelled missed-reveal case still
+// counts towards the offender's miss count.
+
+#[derive(Accounts)]
+pub struct CancelCase<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [SLASHING_CONFIG_SEED],
+        bump = config.bump,
+        has_one = authority @ M0SlashingError::Unauthorized
+    )]
+    pub config: Account<'info, SlashingConfig>,
+
+    #[account(
+        mut,
+        seeds = [BOND_VAULT_SEED, &[bond.kind as u8], bond.operator.as_ref()],
+        bump = bond.bump
+    )]
+    pub bond: Account<'info, OperatorBond>,
+
+    #[account(
+        mut,
+        seeds = [SLASH_CASE_SEED, bond.key().as_ref(), &case.evidence_hash],
+        bump = case.bump,
+        has_one = bond @ M0SlashingError::InvalidParameter
+    )]
+    pub case: Account<'info, SlashCase>,
+}
+
+pub fn handler(ctx: Context<CancelCase>) -> Result<()> {
+    let case = &mut ctx.accounts.case;
+    if case.state != CaseState::Pending {
+        return err!(M0SlashingError::CaseNotPending);
+    }
+
+    let now = Clock::get()?.slot;
+    if now >= case.executable_at_slot {
+        return err!(M0SlashingError::DisputeWindowClosed);
+    }
+
+    case.state = CaseState::Cancelled;
+    case.closed_at_slot = now;
+
+    let bond = &mut ctx.accounts.bond;
+    bond.open_cases = bond.open_cases.saturating_sub(1);
+    bond.last_updated_slot = now;
+
+    emit!(SlashCaseCancelled {
+        case: case.key(),
+        offender: case.offender,
+        cancelled_by: ctx.accounts.authority.key(),
+        cancelled_at_slot: now,
+    });
+
+    Ok(())
+}
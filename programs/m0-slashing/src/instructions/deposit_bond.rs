@@ -0,0 +1,62 @@
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::constants::*;
+use crate::error::M0SlashingError;
+use crate::events::*;
+use crate::state::bond::{BondState, OperatorBond, OperatorKind};
+
+#[derive(Accounts)]
+#[instruction(kind: OperatorKind)]
+pub struct DepositBond<'info> {
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = OperatorBond::LEN,
+        seeds = [BOND_VAULT_SEED, &[kind as u8], operator.key().as_ref()],
+        bump
+    )]
+    pub bond: Account<'info, OperatorBond>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<DepositBond>, kind: OperatorKind, amount: u64) -> Result<()> {
+    if amount == 0 {
+        return err!(M0SlashingError::InvalidParameter);
+    }
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.operator.to_account_info(),
+                to: ctx.accounts.bond.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let b = &mut ctx.accounts.bond;
+    if b.operator == Pubkey::default() {
+        b.operator = ctx.accounts.operator.key();
+        b.kind = kind;
+        b.state = BondState::Locked;
+        b.bump = *ctx.bumps.get("bond").unwrap();
+    }
+    b.amount = b.amount.checked_add(amount).ok_or(M0SlashingError::MathOverflow)?;
+    b.last_updated_slot = Clock::get()?.slot;
+
+    emit!(BondDeposited {
+        bond: b.key(),
+        operator: b.operator,
+        kind,
+        amount,
+        total: b.amount,
+    });
+
+    Ok(())
+}
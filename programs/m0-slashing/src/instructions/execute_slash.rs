@@ -0,0 +1,97 @@
+
+use anchor_lang::prelude::*;
+use m0_fee_router::state::vaults::{FeeVault, VAULT_SEED};
+use crate::constants::*;
+use crate::error::M0SlashingError;
+use crate::events::*;
+use crate::state::bond::OperatorBond;
+use crate::state::case::{CaseState, SlashCase};
+use crate::state::config::SlashingConfig;
+
+// Permissionless once the dispute window has passed. The penalty applies to the bond
+// balance at execution time; the reporter's share is paid directly and the remainder
+// is moved into the fee-router vault for distribution.
+
+#[derive(Accounts)]
+pub struct ExecuteSlash<'info> {
+    #[account(
+        seeds = [SLASHING_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, SlashingConfig>,
+
+    #[account(
+        mut,
+        seeds = [BOND_VAULT_SEED, &[bond.kind as u8], bond.operator.as_ref()],
+        bump = bond.bump
+    )]
+    pub bond: Account<'info, OperatorBond>,
+
+    #[account(
+        mut,
+        seeds = [SLASH_CASE_SEED, bond.key().as_ref(), &case.evidence_hash],
+        bump = case.bump,
+        has_one = bond @ M0SlashingError::InvalidParameter,
+        has_one = reporter @ M0SlashingError::InvalidParameter
+    )]
+    pub case: Account<'info, SlashCase>,
+
+    #[account(mut)]
+    pub reporter: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED],
+        bump = fee_vault.bump,
+        seeds::program = m0_fee_router::ID
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+}
+
+pub fn handler(ctx: Context<ExecuteSlash>) -> Result<()> {
+    let case = &mut ctx.accounts.case;
+    if case.state != CaseState::Pending {
+        return err!(M0SlashingError::CaseNotPending);
+    }
+
+    let now = Clock::get()?.slot;
+    if now < case.executable_at_slot {
+        return err!(M0SlashingError::DisputeWindowOpen);
+    }
+
+    let bond = &mut ctx.accounts.bond;
+    let amount = (bond.amount as u128)
+        .checked_mul(case.penalty_bps as u128)
+        .ok_or(M0SlashingError::MathOverflow)?
+        / BPS_DENOMINATOR as u128;
+    let amount = amount as u64;
+    let reward = ((amount as u128) * (ctx.accounts.config.params.reporter_reward_bps as u128) / BPS_DENOMINATOR as u128) as u64;
+    let routed = amount - reward;
+
+    **bond.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.reporter.to_account_info().try_borrow_mut_lamports()? += reward;
+    **ctx.accounts.fee_vault.to_account_info().try_borrow_mut_lamports()? += routed;
+
+    bond.amount -= amount;
+    bond.unbonding_amount = bond.unbonding_amount.min(bond.amount);
+    bond.total_slashed = bond.total_slashed.saturating_add(amount);
+    bond.open_cases = bond.open_cases.saturating_sub(1);
+    bond.last_updated_slot = now;
+
+    case.state = CaseState::Executed;
+    case.amount_slashed = amount;
+    case.reporter_reward = reward;
+    case.closed_at_slot = now;
+
+    emit!(SlashExecuted {
+        case: case.key(),
+        bond: bond.key(),
+        offender: case.offender,
+        amount_slashed: amount,
+        reporter_reward: reward,
+        routed_to_fee_vault: routed,
+        executed_at_slot: now,
+    });
+
+    Ok(())
+}
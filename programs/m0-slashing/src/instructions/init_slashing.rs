@@ -0,0 +1,39 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::events::*;
+use crate::state::config::{SlashingConfig, SlashingParams};
+
+#[derive(Accounts)]
+pub struct InitSlashing<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = SlashingConfig::LEN,
+        seeds = [SLASHING_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, SlashingConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitSlashing>, params: SlashingParams) -> Result<()> {
+    params.validate()?;
+
+    let cfg = &mut ctx.accounts.config;
+    cfg.authority = ctx.accounts.authority.key();
+    cfg.pending_authority = None;
+    cfg.params = params;
+    cfg.bump = *ctx.bumps.get("config").unwrap();
+
+    emit!(SlashingParamsSet {
+        authority: cfg.authority,
+        params,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,12 @@
+pub mod accept_authority;
+pub mod cancel_authority_transfer;
+pub mod cancel_case;
+pub mod deposit_bond;
+pub mod execute_slash;
+pub mod init_slashing;
+pub mod propose_authority;
+pub mod report_double_sign;
+pub mod report_missed_reveal;
+pub mod request_unbond;
+pub mod set_params;
+pub mod withdraw_bond;
@@ -0,0 +1,33 @@
+
+use anchor_lang::prelude::*;
+use crate::error::M0SlashingError;
+use crate::events::*;
+use crate::constants::*;
+use crate::state::config::SlashingConfig;
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SLASHING_CONFIG_SEED],
+        bump = config.bump,
+        has_one = authority @ M0SlashingError::Unauthorized
+    )]
+    pub config: Account<'info, SlashingConfig>,
+}
+
+pub fn handler(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    let r = &mut ctx.accounts.config;
+    if new_authority == Pubkey::default() || new_authority == r.authority {
+        return err!(M0SlashingError::InvalidParameter);
+    }
+    r.pending_authority = Some(new_authority);
+    emit!(AuthorityTransferProposed {
+        authority: r.authority,
+        pending_authority: new_authority,
+        proposed_at_slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
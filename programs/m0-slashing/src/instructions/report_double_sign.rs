@@ -0,0 +1,147 @@
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as ix_sysvar;
+use m0_oracle::constants::{EPOCH_SEED, SIGNER_SET_SEED};
+use m0_oracle::state::signer_set::SignerSet;
+use m0_oracle::utils::hashing::hash_signature_message;
+use m0_oracle::verify::signature::verify_threshold_signatures;
+use sha2::{Digest, Sha256};
+use crate::constants::*;
+use crate::error::M0SlashingError;
+use crate::events::*;
+use crate::state::bond::{OperatorBond, OperatorKind};
+use crate::state::case::{Offense, SlashCase, Severity};
+use crate::state::config::SlashingConfig;
+
+// Double-sign evidence: two Ed25519Program instructions in this transaction, both from
+// `offender`, over signature messages for the same (market, epoch, signer_set_id,
+// publish_epoch_id, sequence) but different bundle hashes. `epoch` must be the oracle
+// epoch PDA of `market` for `publish_epoch_id`. The pair is ordered before hashing so
+// the same evidence always maps to the same case PDA.
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct DoubleSignEvidence {
+    pub offender: Pubkey,
+    pub market: Pubkey, // m0-oracle Market PDA
+    pub epoch: Pubkey,  // m0-oracle Epoch PDA
+    pub signer_set_id: u64,
+    pub publish_epoch_id: u64,
+    pub sequence: u64,
+    pub bundle_hash_a: [u8; 32],
+    pub bundle_hash_b: [u8; 32],
+}
+
+impl DoubleSignEvidence {
+    pub fn evidence_hash(&self) -> [u8; 32] {
+        let (lo, hi) = if self.bundle_hash_a <= self.bundle_hash_b {
+            (self.bundle_hash_a, self.bundle_hash_b)
+        } else {
+            (self.bundle_hash_b, self.bundle_hash_a)
+        };
+        let mut h = Sha256::new();
+        h.update(b"M0_DOUBLE_SIGN_V1");
+        h.update(self.offender.as_ref());
+        h.update(self.market.as_ref());
+        h.update(self.epoch.as_ref());
+        h.update(self.signer_set_id.to_le_bytes());
+        h.update(self.publish_epoch_id.to_le_bytes());
+        h.update(self.sequence.to_le_bytes());
+        h.update(lo);
+        h.update(hi);
+        h.finalize().into()
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(evidence: DoubleSignEvidence)]
+pub struct ReportDoubleSign<'info> {
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    #[account(
+        seeds = [SLASHING_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, SlashingConfig>,
+
+    #[account(
+        seeds = [SIGNER_SET_SEED, &evidence.signer_set_id.to_le_bytes()],
+        bump = signer_set.bump,
+        seeds::program = m0_oracle::ID
+    )]
+    pub signer_set: Account<'info, SignerSet>,
+
+    #[account(
+        mut,
+        seeds = [BOND_VAULT_SEED, &[OperatorKind::Signer as u8], evidence.offender.as_ref()],
+        bump = bond.bump
+    )]
+    pub bond: Account<'info, OperatorBond>,
+
+    #[account(
+        init,
+        payer = reporter,
+        space = SlashCase::LEN,
+        seeds = [SLASH_CASE_SEED, bond.key().as_ref(), &evidence.evidence_hash()],
+        bump
+    )]
+    pub case: Account<'info, SlashCase>,
+
+    /// CHECK: address is constrained to the instructions sysvar; parsed in m0_oracle::verify::signature.
+    #[account(address = ix_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ReportDoubleSign>, evidence: DoubleSignEvidence) -> Result<()> {
+    let params = ctx.accounts.config.params;
+    if !params.enabled {
+        return err!(M0SlashingError::SlashingDisabled);
+    }
+    if !ctx.accounts.signer_set.pubkeys.contains(&evidence.offender) {
+        return err!(M0SlashingError::NotSignerSetMember);
+    }
+    if evidence.bundle_hash_a == evidence.bundle_hash_b {
+        return err!(M0SlashingError::NotConflicting);
+    }
+    let (epoch, _) = Pubkey::find_program_address(&[EPOCH_SEED, evidence.market.as_ref(), &evidence.publish_epoch_id.to_le_bytes()], &m0_oracle::ID);
+    if epoch != evidence.epoch {
+        return err!(M0SlashingError::EvidenceMismatch);
+    }
+
+    let ix_info = ctx.accounts.instructions.to_account_info();
+    let offender = [evidence.offender];
+    for bundle_hash in [evidence.bundle_hash_a, evidence.bundle_hash_b] {
+        let msg = hash_signature_message(&bundle_hash, &evidence.market, &evidence.epoch, evidence.signer_set_id, evidence.publish_epoch_id, evidence.sequence);
+        verify_threshold_signatures(&ix_info, &msg, &offender, 1)?;
+    }
+
+    let now = Clock::get()?.slot;
+    let evidence_hash = evidence.evidence_hash();
+    let bond = &mut ctx.accounts.bond;
+    bond.open_cases = bond.open_cases.checked_add(1).ok_or(M0SlashingError::MathOverflow)?;
+    bond.last_updated_slot = now;
+
+    let case = &mut ctx.accounts.case;
+    case.open(bond.key(), evidence.offender, Offense::DoubleSign, Severity::Critical, &params, evidence_hash, ctx.accounts.reporter.key(), now);
+    case.market = evidence.market;
+    case.epoch = evidence.epoch;
+    case.publish_epoch_id = evidence.publish_epoch_id;
+    case.sequence = evidence.sequence;
+    case.bump = *ctx.bumps.get("case").unwrap();
+
+    emit!(SlashCaseOpened {
+        case: case.key(),
+        bond: case.bond,
+        offender: case.offender,
+        offense: case.offense,
+        severity: case.severity,
+        penalty_bps: case.penalty_bps,
+        evidence_hash,
+        reporter: case.reporter,
+        executable_at_slot: case.executable_at_slot,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+
+use anchor_lang::prelude::*;
+use m0_oracle::constants::COMMIT_SEED;
+use m0_oracle::state::commit::CommitRecord;
+use crate::constants::*;
+use crate::error::M0SlashingError;
+use crate::events::*;
+use crate::state::bond::{OperatorBond, OperatorKind};
+use crate::state::case::{Offense, SlashCase, Severity};
+use crate::state::config::SlashingConfig;
+
+// Evidence is the m0-oracle commit marked expired by expire_commit. The commit key is
+// the evidence hash, so each missed reveal can be reported once. Misses up to
+// `missed_reveal_grace` are Minor, later ones Major.
+
+#[derive(Accounts)]
+pub struct ReportMissedReveal<'info> {
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    #[account(
+        seeds = [SLASHING_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, SlashingConfig>,
+
+    #[account(
+        seeds = [COMMIT_SEED, commit.epoch.as_ref(), commit.committer.as_ref()],
+        bump = commit.bump,
+        seeds::program = m0_oracle::ID
+    )]
+    pub commit: Account<'info, CommitRecord>,
+
+    #[account(
+        mut,
+        seeds = [BOND_VAULT_SEED, &[OperatorKind::Submitter as u8], commit.committer.as_ref()],
+        bump = bond.bump
+    )]
+    pub bond: Account<'info, OperatorBond>,
+
+    #[account(
+        init,
+        payer = reporter,
+        space = SlashCase::LEN,
+        seeds = [SLASH_CASE_SEED, bond.key().as_ref(), commit.key().as_ref()],
+        bump
+    )]
+    pub case: Account<'info, SlashCase>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ReportMissedReveal>) -> Result<()> {
+    let params = ctx.accounts.config.params;
+    if !params.enabled {
+        return err!(M0SlashingError::SlashingDisabled);
+    }
+    let commit = &ctx.accounts.commit;
+    if !commit.expired {
+        return err!(M0SlashingError::CommitNotExpired);
+    }
+
+    let now = Clock::get()?.slot;
+    let bond = &mut ctx.accounts.bond;
+    bond.missed_reveals = bond.missed_reveals.saturating_add(1);
+    bond.open_cases = bond.open_cases.checked_add(1).ok_or(M0SlashingError::MathOverflow)?;
+    bond.last_updated_slot = now;
+    let severity = if bond.missed_reveals > params.missed_reveal_grace { Severity::Major } else { Severity::Minor };
+
+    let evidence_hash = commit.key().to_bytes();
+    let case = &mut ctx.accounts.case;
+    case.open(bond.key(), commit.committer, Offense::MissedReveal, severity, &params, evidence_hash, ctx.accounts.reporter.key(), now);
+    case.market = commit.market;
+    case.epoch = commit.epoch;
+    case.bump = *ctx.bumps.get("case").unwrap();
+
+    emit!(SlashCaseOpened {
+        case: case.key(),
+        bond: case.bond,
+        offender: case.offender,
+        offense: case.offense,
+        severity: case.severity,
+        penalty_bps: case.penalty_bps,
+        evidence_hash,
+        reporter: case.reporter,
+        executable_at_slot: case.executable_at_slot,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::M0SlashingError;
+use crate::events::*;
+use crate::state::bond::{BondState, OperatorBond};
+use crate::state::config::SlashingConfig;
+
+// A new request replaces any pending one and restarts the unbonding period.
+// Unbonding funds stay slashable until withdrawn.
+
+#[derive(Accounts)]
+pub struct RequestUnbond<'info> {
+    pub operator: Signer<'info>,
+
+    #[account(
+        seeds = [SLASHING_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, SlashingConfig>,
+
+    #[account(
+        mut,
+        seeds = [BOND_VAULT_SEED, &[bond.kind as u8], operator.key().as_ref()],
+        bump = bond.bump
+    )]
+    pub bond: Account<'info, OperatorBond>,
+}
+
+pub fn handler(ctx: Context<RequestUnbond>, amount: u64) -> Result<()> {
+    let b = &mut ctx.accounts.bond;
+    if amount == 0 {
+        return err!(M0SlashingError::InvalidParameter);
+    }
+    if amount > b.amount {
+        return err!(M0SlashingError::InsufficientBond);
+    }
+
+    let now = Clock::get()?.slot;
+    b.state = BondState::Unlocking;
+    b.unbonding_amount = amount;
+    b.unbonding_started_slot = now;
+    b.last_updated_slot = now;
+
+    emit!(UnbondRequested {
+        bond: b.key(),
+        operator: b.operator,
+        amount,
+        withdrawable_at_slot: now.saturating_add(ctx.accounts.config.params.unbonding_period_slots),
+    });
+
+    Ok(())
+}
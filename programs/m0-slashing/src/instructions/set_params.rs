@@ -0,0 +1,35 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::M0SlashingError;
+use crate::events::*;
+use crate::state::config::{SlashingConfig, SlashingParams};
+
+// Penalty bps are captured when a case is opened, so changes only affect new cases.
+
+#[derive(Accounts)]
+pub struct SetParams<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SLASHING_CONFIG_SEED],
+        bump = config.bump,
+        has_one = authority @ M0SlashingError::Unauthorized
+    )]
+    pub config: Account<'info, SlashingConfig>,
+}
+
+pub fn handler(ctx: Context<SetParams>, params: SlashingParams) -> Result<()> {
+    params.validate()?;
+
+    let cfg = &mut ctx.accounts.config;
+    cfg.params = params;
+
+    emit!(SlashingParamsSet {
+        authority: cfg.authority,
+        params,
+    });
+
+    Ok(())
+}
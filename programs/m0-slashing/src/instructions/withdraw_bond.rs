@@ -0,0 +1,61 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::M0SlashingError;
+use crate::events::*;
+use crate::state::bond::{BondState, OperatorBond};
+use crate::state::config::SlashingConfig;
+
+#[derive(Accounts)]
+pub struct WithdrawBond<'info> {
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    #[account(
+        seeds = [SLASHING_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, SlashingConfig>,
+
+    #[account(
+        mut,
+        seeds = [BOND_VAULT_SEED, &[bond.kind as u8], operator.key().as_ref()],
+        bump = bond.bump
+    )]
+    pub bond: Account<'info, OperatorBond>,
+}
+
+pub fn handler(ctx: Context<WithdrawBond>) -> Result<()> {
+    let b = &mut ctx.accounts.bond;
+    if b.state != BondState::Unlocking || b.unbonding_amount == 0 {
+        return err!(M0SlashingError::NoUnbondingRequest);
+    }
+    if b.open_cases > 0 {
+        return err!(M0SlashingError::BondHasOpenCases);
+    }
+
+    let now = Clock::get()?.slot;
+    if now < b.unbonding_started_slot.saturating_add(ctx.accounts.config.params.unbonding_period_slots) {
+        return err!(M0SlashingError::UnbondingNotElapsed);
+    }
+
+    // slashes during unbonding may have reduced the bond below the request
+    let amount = b.unbonding_amount.min(b.amount);
+    **b.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.operator.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    b.amount -= amount;
+    b.unbonding_amount = 0;
+    b.unbonding_started_slot = 0;
+    b.state = BondState::Locked;
+    b.last_updated_slot = now;
+
+    emit!(BondWithdrawn {
+        bond: b.key(),
+        operator: b.operator,
+        amount,
+        remaining: b.amount,
+    });
+
+    Ok(())
+}
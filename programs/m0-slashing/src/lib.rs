@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod error;
+pub mod events;
+pub mod instructions;
+pub mod state;
+
+use instructions::*;
+
+declare_id!("M0Slash1111111111111111111111111111111111");
+
+#[program]
+pub mod m0_slashing {
+    use super::*;
+
+    pub fn init_slashing(ctx: Context<init_slashing::InitSlashing>, params: state::config::SlashingParams) -> Result<()> {
+        init_slashing::handler(ctx, params)
+    }
+
+    pub fn set_params(ctx: Context<set_params::SetParams>, params: state::config::SlashingParams) -> Result<()> {
+        set_params::handler(ctx, params)
+    }
+
+    pub fn deposit_bond(ctx: Context<deposit_bond::DepositBond>, kind: state::bond::OperatorKind, amount: u64) -> Result<()> {
+        deposit_bond::handler(ctx, kind, amount)
+    }
+
+    pub fn request_unbond(ctx: Context<request_unbond::RequestUnbond>, amount: u64) -> Result<()> {
+        request_unbond::handler(ctx, amount)
+    }
+
+    pub fn withdraw_bond(ctx: Context<withdraw_bond::WithdrawBond>) -> Result<()> {
+        withdraw_bond::handler(ctx)
+    }
+
+    pub fn report_double_sign(ctx: Context<report_double_sign::ReportDoubleSign>, evidence: report_double_sign::DoubleSignEvidence) -> Result<()> {
+        report_double_sign::handler(ctx, evidence)
+    }
+
+    pub fn report_missed_reveal(ctx: Context<report_missed_reveal::ReportMissedReveal>) -> Result<()> {
+        report_missed_reveal::handler(ctx)
+    }
+
+    pub fn cancel_case(ctx: Context<cancel_case::CancelCase>) -> Result<()> {
+        cancel_case::handler(ctx)
+    }
+
+    pub fn execute_slash(ctx: Context<execute_slash::ExecuteSlash>) -> Result<()> {
+        execute_slash::handler(ctx)
+    }
+
+    pub fn propose_authority(ctx: Context<propose_authority::ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        propose_authority::handler(ctx, new_authority)
+    }
+
+    pub fn accept_authority(ctx: Context<accept_authority::AcceptAuthority>) -> Result<()> {
+        accept_authority::handler(ctx)
+    }
+
+    pub fn cancel_authority_transfer(ctx: Context<cancel_authority_transfer::CancelAuthorityTransfer>) -> Result<()> {
+        cancel_authority_transfer::handler(ctx)
+    }
+}
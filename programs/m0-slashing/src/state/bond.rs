@@ -0,0 +1,35 @@
+
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum OperatorKind {
+    Signer,    // attests bundle hashes as a signer set member
+    Submitter, // commits and reveals on m0-oracle
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum BondState {
+    Locked,
+    Unlocking,
+}
+
+// SOL bond held as lamports on this PDA: [BOND_VAULT_SEED, kind, operator].
+// `amount` excludes the rent-exempt reserve.
+#[account]
+pub struct OperatorBond {
+    pub operator: Pubkey,
+    pub kind: OperatorKind,
+    pub amount: u64,
+    pub state: BondState,
+    pub unbonding_amount: u64,
+    pub unbonding_started_slot: u64,
+    pub open_cases: u32,
+    pub missed_reveals: u32,
+    pub total_slashed: u64,
+    pub last_updated_slot: u64,
+    pub bump: u8,
+}
+
+impl OperatorBond {
+    pub const LEN: usize = 8 + 32 + 1 + 8 + 1 + 8 + 8 + 4 + 4 + 8 + 8 + 1;
+}
@@ -0,0 +1,83 @@
+
+use anchor_lang::prelude::*;
+use crate::state::config::SlashingParams;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Offense {
+    DoubleSign,   // two signatures over different bundle hashes for one (market, epoch, signer_set, sequence)
+    MissedReveal, // m0-oracle CommitRecord expired without reveal
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Minor,
+    Major,
+    Critical,
+}
+
+impl Severity {
+    pub fn penalty_bps(&self, params: &SlashingParams) -> u16 {
+        match self {
+            Severity::Minor => params.penalty_minor_bps,
+            Severity::Major => params.penalty_major_bps,
+            Severity::Critical => params.penalty_critical_bps,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum CaseState {
+    Pending,
+    Cancelled,
+    Executed,
+}
+
+// One case per (bond, evidence): [SLASH_CASE_SEED, bond, evidence_hash].
+// Penalty bps are fixed when the case is opened; execution waits for the dispute window.
+#[account]
+pub struct SlashCase {
+    pub bond: Pubkey,
+    pub offender: Pubkey,
+    pub offense: Offense,
+    pub severity: Severity,
+    pub penalty_bps: u16,
+    pub evidence_hash: [u8; 32],
+    pub market: Pubkey,      // oracle market PDA
+    pub epoch: Pubkey,       // oracle epoch PDA
+    pub publish_epoch_id: u64, // double sign only
+    pub sequence: u64,         // double sign only
+    pub reporter: Pubkey,
+    pub opened_at_slot: u64,
+    pub executable_at_slot: u64,
+    pub state: CaseState,
+    pub amount_slashed: u64,
+    pub reporter_reward: u64,
+    pub closed_at_slot: u64,
+    pub bump: u8,
+}
+
+impl SlashCase {
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 1 + 2 + 32 + 32 + 32 + 8 + 8 + 32 + 8 + 8 + 1 + 8 + 8 + 8 + 1;
+
+    /// Fills the fields shared by every offense; context fields are left zeroed.
+    #[allow(clippy::too_many_arguments)]
+    pub fn open(&mut self, bond: Pubkey, offender: Pubkey, offense: Offense, severity: Severity, params: &SlashingParams, evidence_hash: [u8; 32], reporter: Pubkey, now: u64) {
+        self.bond = bond;
+        self.offender = offender;
+        self.offense = offense;
+        self.severity = severity;
+        self.penalty_bps = severity.penalty_bps(params);
+        self.evidence_hash = evidence_hash;
+        self.market = Pubkey::default();
+        self.epoch = Pubkey::default();
+        self.publish_epoch_id = 0;
+        self.sequence = 0;
+        self.reporter = reporter;
+        self.opened_at_slot = now;
+        self.executable_at_slot = now.saturating_add(params.dispute_window_slots);
+        self.state = CaseState::Pending;
+        self.amount_slashed = 0;
+        self.reporter_reward = 0;
+        self.closed_at_slot = 0;
+    }
+}
@@ -0,0 +1,45 @@
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::M0SlashingError;
+
+// Mirrors the [slashing] section of config/risk/slashing-params.toml, in slots.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct SlashingParams {
+    pub enabled: bool,
+    pub penalty_minor_bps: u16,
+    pub penalty_major_bps: u16,
+    pub penalty_critical_bps: u16,
+    pub missed_reveal_grace: u32, // missed reveals charged as minor before escalating to major
+    pub dispute_window_slots: u64,
+    pub unbonding_period_slots: u64,
+    pub reporter_reward_bps: u16, // share of each slash paid to the reporter; rest goes to m0-fee-router
+}
+
+impl SlashingParams {
+    pub const LEN: usize = 1 + 2 + 2 + 2 + 4 + 8 + 8 + 2;
+
+    pub fn validate(&self) -> Result<()> {
+        let bps = [self.penalty_minor_bps, self.penalty_major_bps, self.penalty_critical_bps, self.reporter_reward_bps];
+        if bps.iter().any(|b| *b as u64 > BPS_DENOMINATOR) {
+            return err!(M0SlashingError::InvalidParameter);
+        }
+        // unbonding must outlast the dispute window so open cases can't be dodged
+        if self.unbonding_period_slots < self.dispute_window_slots {
+            return err!(M0SlashingError::InvalidParameter);
+        }
+        Ok(())
+    }
+}
+
+#[account]
+pub struct SlashingConfig {
+    pub authority: Pubkey,
+    pub pending_authority: Option<Pubkey>,
+    pub params: SlashingParams,
+    pub bump: u8,
+}
+
+impl SlashingConfig {
+    pub const LEN: usize = 8 + 32 + (1 + 32) + SlashingParams::LEN + 1;
+}
@@ -0,0 +1,3 @@
+pub mod bond;
+pub mod case;
+pub mod config;
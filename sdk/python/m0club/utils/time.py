@@ -0,0 +1,47 @@
+"""Epoch/tick rounding shared with m0-oracle (docs/protocol-spec/epoch-rounding.md).
+
+Windows are [window_start_ms, window_end_ms); the on-chain epoch_id is the index.
+"""
+
+import time
+from dataclasses import dataclass
+
+EPOCH_ORIGIN_MS = 0
+
+
+@dataclass(frozen=True)
+class EpochWindow:
+    epoch_id: int
+    window_start_ms: int
+    window_end_ms: int
+
+
+@dataclass(frozen=True)
+class Tick:
+    tick_index: int
+    tick_start_ms: int
+    tick_end_ms: int
+
+
+def epoch_window_of(epoch_id: int, epoch_window_ms: int) -> EpochWindow:
+    start = EPOCH_ORIGIN_MS + epoch_id * epoch_window_ms
+    return EpochWindow(epoch_id, start, start + epoch_window_ms)
+
+
+def epoch_window_at(t_ms: int, epoch_window_ms: int) -> EpochWindow:
+    if epoch_window_ms <= 0:
+        raise ValueError("epoch_window_ms must be > 0")
+    return epoch_window_of(max(0, t_ms - EPOCH_ORIGIN_MS) // epoch_window_ms, epoch_window_ms)
+
+
+def tick_at(t_ms: int, window: EpochWindow, publish_cadence_ms: int) -> Tick:
+    if publish_cadence_ms <= 0:
+        raise ValueError("publish_cadence_ms must be > 0")
+    t = min(max(t_ms, window.window_start_ms), window.window_end_ms - 1)
+    idx = (t - window.window_start_ms) // publish_cadence_ms
+    start = window.window_start_ms + idx * publish_cadence_ms
+    return Tick(idx, start, min(start + publish_cadence_ms, window.window_end_ms))
+
+
+def current_epoch(epoch_window_ms: int) -> EpochWindow:
+    return epoch_window_at(int(time.time() * 1000), epoch_window_ms)
@@ -1,6 +1,91 @@
+use anyhow::Context;
+use base64::Engine;
 
 pub const M0_ORACLE_PROGRAM_ID: &str = "M0Orac1e111111111111111111111111111111111";
 
 pub fn program_id() -> &'static str {
     M0_ORACLE_PROGRAM_ID
 }
+
+// RevealHistory: per-market ring buffer PDA, seeds ["history", market].
+pub const HISTORY_SEED: &[u8] = b"history";
+pub const REVEAL_HISTORY_CAPACITY: usize = 64;
+
+const HISTORY_HEADER_LEN: usize = 8 + 32 + 8 + 4 + 4 + 1 + 7;
+const HISTORY_ENTRY_LEN: usize = 32 + 8 + 8 + 8 + 8 + 32;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevealHistoryEntry {
+    pub bundle_hash: [u8; 32], // merkle root for chunked reveals
+    pub sequence: u64,
+    pub signer_set_id: u64,
+    pub epoch_id: u64,
+    pub slot: u64,
+    pub revealer: String, // base58
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevealHistory {
+    pub market: String,
+    pub total_reveals: u64,
+    pub entries: Vec<RevealHistoryEntry>, // newest first
+}
+
+fn u64_at(data: &[u8], at: usize) -> u64 {
+    u64::from_le_bytes(data[at..at + 8].try_into().expect("8 bytes"))
+}
+
+fn u32_at(data: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes(data[at..at + 4].try_into().expect("4 bytes"))
+}
+
+/// Decodes raw RevealHistory account data (including the 8-byte discriminator).
+pub fn decode_reveal_history(data: &[u8], limit: usize) -> anyhow::Result<RevealHistory> {
+    let need = HISTORY_HEADER_LEN + HISTORY_ENTRY_LEN * REVEAL_HISTORY_CAPACITY;
+    if data.len() < need {
+        anyhow::bail!("RevealHistory: account too small ({} bytes, need {need})", data.len());
+    }
+
+    let head = u32_at(data, 48) as usize;
+    let count = u32_at(data, 52) as usize;
+    let entries = (0..limit.min(count))
+        .map(|i| {
+            let idx = (head + REVEAL_HISTORY_CAPACITY - 1 - i) % REVEAL_HISTORY_CAPACITY;
+            let o = HISTORY_HEADER_LEN + idx * HISTORY_ENTRY_LEN;
+            RevealHistoryEntry {
+                bundle_hash: data[o..o + 32].try_into().expect("32 bytes"),
+                sequence: u64_at(data, o + 32),
+                signer_set_id: u64_at(data, o + 40),
+                epoch_id: u64_at(data, o + 48),
+                slot: u64_at(data, o + 56),
+                revealer: bs58::encode(&data[o + 64..o + 96]).into_string(),
+            }
+        })
+        .collect();
+
+    Ok(RevealHistory {
+        market: bs58::encode(&data[8..40]).into_string(),
+        total_reveals: u64_at(data, 40),
+        entries,
+    })
+}
+
+/// Reads recent reveals straight from a Solana RPC node; no indexer required.
+pub async fn fetch_reveal_history(http: &reqwest::Client, rpc_url: &str, history_address: &str, limit: usize) -> anyhow::Result<RevealHistory> {
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "getAccountInfo",
+        "params": [history_address, { "encoding": "base64", "commitment": "confirmed" }],
+    });
+    let res: serde_json::Value = http.post(rpc_url).json(&body).send().await.context("rpc request failed")?.json().await.context("decode rpc response failed")?;
+    if let Some(err) = res.get("error") {
+        anyhow::bail!("getAccountInfo: {err}");
+    }
+    let b64 = res
+        .pointer("/result/value/data/0")
+        .and_then(|v| v.as_str())
+        .with_context(|| format!("RevealHistory not found: {history_address}"))?;
+    let data = base64::engine::general_purpose::STANDARD.decode(b64).context("decode account data failed")?;
+    decode_reveal_history(&data, limit)
+}
@@ -0,0 +1,17 @@
+use m0club::utils::time::{epoch_window_at, tick_at};
+
+#[test]
+fn matches_on_chain_rounding() {
+    let w = epoch_window_at(1_767_529_296_000, 3_600_000);
+    assert_eq!(w.epoch_id, 490_980);
+    assert_eq!(w.window_start_ms, 490_980 * 3_600_000);
+    assert_eq!(w.window_end_ms, w.window_start_ms + 3_600_000);
+    assert_eq!(epoch_window_at(w.window_end_ms, 3_600_000).epoch_id, w.epoch_id + 1);
+}
+
+#[test]
+fn last_tick_is_inside_window() {
+    let w = epoch_window_at(0, 3_600_000);
+    let t = tick_at(w.window_end_ms, &w, 300_000);
+    assert_eq!((t.tick_index, t.tick_end_ms), (11, w.window_end_ms));
+}
@@ -0,0 +1,39 @@
+use m0club::programs::m0_oracle::{decode_reveal_history, REVEAL_HISTORY_CAPACITY};
+
+const HEADER: usize = 64;
+const ENTRY: usize = 96;
+
+fn account(head: u32, count: u32, total: u64, seqs: &[(usize, u64)]) -> Vec<u8> {
+    let mut data = vec![0u8; HEADER + ENTRY * REVEAL_HISTORY_CAPACITY];
+    data[40..48].copy_from_slice(&total.to_le_bytes());
+    data[48..52].copy_from_slice(&head.to_le_bytes());
+    data[52..56].copy_from_slice(&count.to_le_bytes());
+    for (idx, seq) in seqs {
+        let o = HEADER + idx * ENTRY;
+        data[o + 32..o + 40].copy_from_slice(&seq.to_le_bytes());
+    }
+    data
+}
+
+#[test]
+fn newest_first_before_wrap() {
+    let data = account(3, 3, 3, &[(0, 1), (1, 2), (2, 3)]);
+    let h = decode_reveal_history(&data, 10).unwrap();
+    assert_eq!(h.total_reveals, 3);
+    let seqs: Vec<u64> = h.entries.iter().map(|e| e.sequence).collect();
+    assert_eq!(seqs, vec![3, 2, 1]);
+}
+
+#[test]
+fn newest_first_after_wrap() {
+    let last = REVEAL_HISTORY_CAPACITY - 1;
+    let data = account(1, REVEAL_HISTORY_CAPACITY as u32, 100, &[(0, 100), (last, 99)]);
+    let h = decode_reveal_history(&data, 2).unwrap();
+    let seqs: Vec<u64> = h.entries.iter().map(|e| e.sequence).collect();
+    assert_eq!(seqs, vec![100, 99]);
+}
+
+#[test]
+fn rejects_short_account() {
+    assert!(decode_reveal_history(&[0u8; 16], 1).is_err());
+}
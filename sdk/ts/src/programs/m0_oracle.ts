@@ -2,8 +2,77 @@
 // On-chain program wrapper placeholder (client-side helpers).
 // Integrate Anchor IDL + @solana/web3.js in production.
 
+import { postJson } from "../utils/http";
+import { encodeBase58 } from "../utils/base58";
+
 export const M0_ORACLE_PROGRAM_ID = "M0Orac1e111111111111111111111111111111111";
 
 export function oracleProgramId(): string {
   return M0_ORACLE_PROGRAM_ID;
 }
+
+// RevealHistory: per-market ring buffer PDA, seeds ["history", market].
+export const HISTORY_SEED = "history";
+export const REVEAL_HISTORY_CAPACITY = 64;
+
+const HISTORY_HEADER_LEN = 8 + 32 + 8 + 4 + 4 + 1 + 7;
+const HISTORY_ENTRY_LEN = 32 + 8 + 8 + 8 + 8 + 32;
+
+export interface RevealHistoryEntry {
+  bundleHash: string; // hex; merkle root for chunked reveals
+  sequence: bigint;
+  signerSetId: bigint;
+  epochId: bigint;
+  slot: bigint;
+  revealer: string;   // base58
+}
+
+export interface RevealHistory {
+  market: string;
+  totalReveals: bigint;
+  entries: RevealHistoryEntry[]; // newest first
+}
+
+function toHex(b: Uint8Array): string {
+  return Array.from(b, (x) => x.toString(16).padStart(2, "0")).join("");
+}
+
+/** Decodes raw RevealHistory account data (including the 8-byte discriminator). */
+export function decodeRevealHistory(data: Uint8Array, limit = REVEAL_HISTORY_CAPACITY): RevealHistory {
+  if (data.length < HISTORY_HEADER_LEN + HISTORY_ENTRY_LEN * REVEAL_HISTORY_CAPACITY) {
+    throw new Error(`RevealHistory: account too small (${data.length} bytes)`);
+  }
+  const view = new DataView(data.buffer, data.byteOffset, data.byteLength);
+  const market = encodeBase58(data.subarray(8, 40));
+  const totalReveals = view.getBigUint64(40, true);
+  const head = view.getUint32(48, true);
+  const count = view.getUint32(52, true);
+
+  const entries: RevealHistoryEntry[] = [];
+  for (let i = 0; i < Math.min(limit, count); i++) {
+    const idx = (head + REVEAL_HISTORY_CAPACITY - 1 - i) % REVEAL_HISTORY_CAPACITY;
+    const o = HISTORY_HEADER_LEN + idx * HISTORY_ENTRY_LEN;
+    entries.push({
+      bundleHash: toHex(data.subarray(o, o + 32)),
+      sequence: view.getBigUint64(o + 32, true),
+      signerSetId: view.getBigUint64(o + 40, true),
+      epochId: view.getBigUint64(o + 48, true),
+      slot: view.getBigUint64(o + 56, true),
+      revealer: encodeBase58(data.subarray(o + 64, o + 96))
+    });
+  }
+  return { market, totalReveals, entries };
+}
+
+/** Reads recent reveals straight from a Solana RPC node; no indexer required. */
+export async function fetchRevealHistory(rpcUrl: string, historyAddress: string, limit?: number): Promise<RevealHistory> {
+  const res = await postJson<{ result?: { value: { data: [string, string] } | null }; error?: { message: string } }>(rpcUrl, {
+    jsonrpc: "2.0",
+    id: 1,
+    method: "getAccountInfo",
+    params: [historyAddress, { encoding: "base64", commitment: "confirmed" }]
+  });
+  if (res.error) throw new Error(`getAccountInfo: ${res.error.message}`);
+  if (!res.result?.value) throw new Error(`RevealHistory not found: ${historyAddress}`);
+  return decodeRevealHistory(Buffer.from(res.result.value.data[0], "base64"), limit);
+}
@@ -9,3 +9,16 @@ export async function getJson<T>(url: string, headers?: Record<string, string>):
   }
   return (await res.json()) as T;
 }
+
+export async function postJson<T>(url: string, body: unknown, headers?: Record<string, string>): Promise<T> {
+  const res = await fetch(url, {
+    method: "POST",
+    headers: { "content-type": "application/json", ...(headers ?? {}) },
+    body: JSON.stringify(body)
+  });
+  if (!res.ok) {
+    const text = await res.text().catch(() => "");
+    throw new Error(`HTTP ${res.status}: ${text}`);
+  }
+  return (await res.json()) as T;
+}
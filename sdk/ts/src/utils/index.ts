@@ -1,2 +1,4 @@
 
 export * from "./http";
+export * from "./base58";
+export * from "./time";
@@ -0,0 +1,41 @@
+// Epoch/tick rounding shared with m0-oracle (docs/protocol-spec/epoch-rounding.md).
+// Windows are [windowStartMs, windowEndMs); the on-chain epoch_id is the index.
+// Millisecond timestamps stay well inside Number.MAX_SAFE_INTEGER.
+export const EPOCH_ORIGIN_MS = 0;
+
+export interface EpochWindow {
+  epochId: number;
+  windowStartMs: number;
+  windowEndMs: number;
+}
+
+export interface Tick {
+  tickIndex: number;
+  tickStartMs: number;
+  tickEndMs: number;
+}
+
+export function epochWindowOf(epochId: number, epochWindowMs: number): EpochWindow {
+  const windowStartMs = EPOCH_ORIGIN_MS + epochId * epochWindowMs;
+  return { epochId, windowStartMs, windowEndMs: windowStartMs + epochWindowMs };
+}
+
+export function epochWindowAt(tMs: number, epochWindowMs: number): EpochWindow {
+  if (epochWindowMs <= 0) throw new Error("epochWindowMs must be > 0");
+  const delta = Math.max(0, tMs - EPOCH_ORIGIN_MS);
+  return epochWindowOf(Math.floor(delta / epochWindowMs), epochWindowMs);
+}
+
+export function tickAt(tMs: number, window: EpochWindow, publishCadenceMs: number): Tick {
+  if (publishCadenceMs <= 0) throw new Error("publishCadenceMs must be > 0");
+  const t = Math.min(Math.max(tMs, window.windowStartMs), window.windowEndMs - 1);
+  const tickIndex = Math.floor((t - window.windowStartMs) / publishCadenceMs);
+  const tickStartMs = window.windowStartMs + tickIndex * publishCadenceMs;
+  return { tickIndex, tickStartMs, tickEndMs: Math.min(tickStartMs + publishCadenceMs, window.windowEndMs) };
+}
+
+// Epoch the chain considers current; the program uses the cluster clock, so expect
+// up to a second of disagreement at the boundary.
+export function currentEpoch(epochWindowMs: number, nowMs: number = Date.now()): EpochWindow {
+  return epochWindowAt(nowMs, epochWindowMs);
+}